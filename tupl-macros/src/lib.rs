@@ -2,10 +2,39 @@
 
 use proc_macro::TokenStream;
 
+mod repeat;
 mod traits;
 
+/// The maximum arity of the tuples the traits are implemented for, selected through the crate features.
+const MAX_ARITY: usize = if cfg!(feature = "large-tuples") {
+	64
+} else if cfg!(feature = "arity-32") {
+	32
+} else if cfg!(feature = "arity-16") {
+	16
+} else {
+	8
+};
+
+/// The maximum arity of the tuples the traits with a const parameter are implemented for, as their implementations grow quadratically with it.
+const MAX_CONST_ARITY: usize = if MAX_ARITY > 32 { 32 } else { MAX_ARITY };
+
 /// This macro is used by the `tupl` crate to generate the necessary trait implementations.
 #[proc_macro]
 pub fn impl_traits(_: TokenStream) -> TokenStream {
-	traits::impl_all_traits().into()
+	traits::impl_all_traits(MAX_ARITY, MAX_CONST_ARITY).into()
+}
+
+/// This macro is used by the `tuple!` macro of the `tupl` crate to repeat a value a given number of times.
+#[doc(hidden)]
+#[proc_macro]
+pub fn repeat_tuple(input: TokenStream) -> TokenStream {
+	repeat::repeat_tuple(input.into()).into()
+}
+
+/// This macro is used by the `tupl` crate to expose the maximum arity of the tuples the traits are implemented for.
+#[doc(hidden)]
+#[proc_macro]
+pub fn max_arity(_: TokenStream) -> TokenStream {
+	proc_macro::TokenTree::from(proc_macro::Literal::usize_unsuffixed(MAX_ARITY)).into()
 }
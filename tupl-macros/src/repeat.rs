@@ -0,0 +1,60 @@
+use proc_macro2::*;
+use quote::{quote, quote_spanned};
+
+pub fn repeat_tuple(input: TokenStream) -> TokenStream {
+	let mut tokens = input.into_iter();
+	let (Some(TokenTree::Group(value)), Some(TokenTree::Punct(semi)), Some(count), None) =
+		(tokens.next(), tokens.next(), tokens.next(), tokens.next())
+	else {
+		return quote!(compile_error!(
+			"expected a value and a count separated by `;`"
+		));
+	};
+
+	if semi.as_char() != ';' {
+		return quote_spanned!(semi.span() => compile_error!("expected `;`"));
+	}
+
+	let Some(count) = parse_count(&count) else {
+		return quote_spanned!(count.span() => compile_error!("expected an integer literal"));
+	};
+
+	let value = value.stream();
+	let repeated = match count {
+		0 => quote!(::core::mem::drop(value)),
+		count => {
+			let clones = (1..count).map(|_| quote!(::core::clone::Clone::clone(&value)));
+			quote!((#(#clones,)* value,))
+		}
+	};
+
+	quote! {{
+		let value = #value;
+		#repeated
+	}}
+}
+
+fn parse_count(count: &TokenTree) -> Option<usize> {
+	let literal = match count {
+		TokenTree::Literal(literal) => literal.clone(),
+		TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+			let mut tokens = group.stream().into_iter();
+			match (tokens.next(), tokens.next()) {
+				(Some(TokenTree::Literal(literal)), None) => literal,
+				_ => return None,
+			}
+		}
+		_ => return None,
+	};
+
+	let literal = literal.to_string().replace('_', "");
+	let (radix, digits) = match literal.get(..2) {
+		Some("0x") => (16, &literal[2..]),
+		Some("0o") => (8, &literal[2..]),
+		Some("0b") => (2, &literal[2..]),
+		_ => (10, &literal[..]),
+	};
+
+	let suffix = digits.find(['u', 'i']).unwrap_or(digits.len());
+	usize::from_str_radix(&digits[..suffix], radix).ok()
+}
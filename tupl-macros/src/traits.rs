@@ -1,28 +1,80 @@
 use proc_macro2::*;
 use quote::{format_ident, quote};
 
-pub const MAX_ARITY: usize = 32;
-
-pub fn impl_all_traits() -> TokenStream {
+pub fn impl_all_traits(max_arity: usize, max_const_arity: usize) -> TokenStream {
 	let mut tokens = TokenStream::new();
-	let idents = (1..=MAX_ARITY)
+	let idents = (1..=max_arity)
 		.map(|i| format_ident!("T{i}"))
 		.collect::<Vec<_>>();
 
 	for i in 0..=idents.len() {
-		tokens.extend(impl_traits(&idents[..i]));
+		tokens.extend(impl_traits(&idents[..i], max_arity));
+	}
+
+	for i in 0..=max_const_arity {
+		tokens.extend(impl_const_traits(&idents[..i], max_const_arity));
 	}
 
+	tokens.extend(impl_swappable(max_const_arity));
+	tokens.extend(impl_field_labels(max_arity));
 	tokens
 }
 
-pub fn impl_traits(idents: &[Ident]) -> TokenStream {
+pub fn impl_field_labels(max_arity: usize) -> TokenStream {
+	let arity = Literal::usize_unsuffixed(max_arity);
+	let labels = (0..max_arity).map(|i| Literal::string(&format!(".{i}")));
+
+	quote! {
+		const FIELD_LABELS: [&str; #arity] = [#(#labels),*];
+	}
+}
+
+pub fn impl_traits(idents: &[Ident], max_arity: usize) -> TokenStream {
 	let mut tokens = impl_tuple(idents);
+	tokens.extend(impl_any(idents));
 	tokens.extend(impl_joinable(idents));
-	tokens.extend(impl_growable(idents));
+	tokens.extend(impl_growable(idents, max_arity));
 	tokens.extend(impl_nonempty(idents));
+	tokens.extend(impl_rotatable(idents));
 	tokens.extend(impl_indexable(idents));
+	tokens.extend(impl_hash_indices(idents));
+	tokens.extend(impl_ref(idents));
+	tokens.extend(impl_homogeneous(idents));
+	tokens.extend(impl_zippable(idents));
+	tokens.extend(impl_zip_append(idents));
+	tokens.extend(impl_interleave(idents, max_arity));
+	tokens.extend(impl_unzippable(idents));
+	tokens.extend(impl_flatten(idents));
+	tokens.extend(impl_concat_arrays(idents));
+	tokens.extend(impl_nested(idents));
+	tokens.extend(impl_transpose_option(idents));
+	tokens.extend(impl_transpose_result(idents));
+	tokens.extend(impl_as_ref(idents));
+	tokens.extend(impl_map_into(idents));
+	tokens.extend(impl_default(idents));
+	tokens.extend(impl_map_each(idents));
+	tokens.extend(impl_fold_each(idents));
+	tokens.extend(impl_enumerate(idents));
 	tokens.extend(impl_fns(idents));
+	tokens.extend(impl_join_futures(idents));
+	tokens.extend(impl_try_join_futures(idents));
+	tokens
+}
+
+pub fn impl_const_traits(idents: &[Ident], max_const_arity: usize) -> TokenStream {
+	let mut tokens = impl_rotate_by(idents, max_const_arity);
+	tokens.extend(impl_replace(idents));
+	tokens.extend(impl_map_at(idents));
+	tokens.extend(impl_insert(idents, max_const_arity));
+	tokens.extend(impl_remove(idents));
+	tokens.extend(impl_swap_remove(idents));
+	tokens.extend(impl_eq_ignoring(idents));
+	tokens.extend(impl_zip_shortest(idents, max_const_arity));
+	tokens.extend(impl_split(idents));
+	tokens.extend(impl_take(idents));
+	tokens.extend(impl_skip(idents));
+	tokens.extend(impl_windows(idents, max_const_arity));
+	tokens.extend(impl_chunks(idents, max_const_arity));
 	tokens
 }
 
@@ -47,6 +99,45 @@ pub fn impl_tuple(idents: &[Ident]) -> TokenStream {
 	}
 }
 
+pub fn impl_any(idents: &[Ident]) -> TokenStream {
+	let arity = Literal::usize_unsuffixed(idents.len());
+	let indices = (0..idents.len())
+		.map(Literal::usize_unsuffixed)
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents: 'static,)*> AnyTuple for (#(#idents,)*) {
+			#[inline]
+			fn get_any(&self, index: usize) -> Option<&dyn core::any::Any> {
+				match index {
+					#(#indices => Some(&self.#indices),)*
+					_ => None,
+				}
+			}
+
+			#[inline]
+			fn get_any_mut(&mut self, index: usize) -> Option<&mut dyn core::any::Any> {
+				match index {
+					#(#indices => Some(&mut self.#indices),)*
+					_ => None,
+				}
+			}
+
+			#[inline]
+			fn is_homogeneous(&self) -> bool {
+				let type_ids: [core::any::TypeId; #arity] = [#(core::any::TypeId::of::<#idents>()),*];
+				type_ids.windows(2).all(|pair| pair[0] == pair[1])
+			}
+
+			#[inline]
+			fn type_ids(&self) -> impl DoubleEndedIterator<Item = core::any::TypeId> + ExactSizeIterator {
+				[#(core::any::TypeId::of::<#idents>()),*].into_iter()
+			}
+		}
+	}
+}
+
 pub fn impl_joinable(idents: &[Ident]) -> TokenStream {
 	let mut tokens = TokenStream::new();
 	for i in 0..=idents.len() {
@@ -69,8 +160,8 @@ pub fn impl_joinable(idents: &[Ident]) -> TokenStream {
 	tokens
 }
 
-pub fn impl_growable(idents: &[Ident]) -> Option<TokenStream> {
-	if idents.len() == MAX_ARITY {
+pub fn impl_growable(idents: &[Ident], max_arity: usize) -> Option<TokenStream> {
+	if idents.len() == max_arity {
 		None
 	} else {
 		Some(quote! {
@@ -135,10 +226,16 @@ pub fn impl_nonempty(idents: &[Ident]) -> Option<TokenStream> {
 				fn truncate_tail(self) -> (Self::TruncateTail, Self::Tail) {
 					((), self.0)
 				}
+
+				#[inline]
+				fn split_first_ref(&self) -> (&Self::Head, <Self::TruncateHead as RefTuple>::Refs<'_>) {
+					(&self.0, ())
+				}
 			}
 		}),
 		[head, rest @ .., tail] => {
 			let tail_idx = Literal::usize_unsuffixed(idents.len() - 1);
+			let rest_indices = (1..idents.len()).map(Literal::usize_unsuffixed);
 			Some(quote! {
 				#[automatically_derived]
 				impl<#head, #(#rest,)* #tail> NonEmptyTuple for (#head, #(#rest,)* #tail) {
@@ -167,6 +264,11 @@ pub fn impl_nonempty(idents: &[Ident]) -> Option<TokenStream> {
 						&mut self.#tail_idx
 					}
 
+					#[inline]
+					fn split_first_ref(&self) -> (&Self::Head, <Self::TruncateHead as RefTuple>::Refs<'_>) {
+						(&self.0, (#(&self.#rest_indices,)*))
+					}
+
 					#[inline]
 					fn truncate_head(self) -> (Self::Head, Self::TruncateHead) {
 						let (#head, #(#rest,)* #tail) = self;
@@ -205,6 +307,54 @@ pub fn impl_nonempty(idents: &[Ident]) -> Option<TokenStream> {
 	}
 }
 
+pub fn impl_rotatable(idents: &[Ident]) -> Option<TokenStream> {
+	let ([head, rest @ ..], [init @ .., tail]) = (idents, idents) else {
+		return None;
+	};
+
+	Some(quote! {
+		#[automatically_derived]
+		impl<#(#idents,)*> RotatableTuple for (#(#idents,)*) {
+			type RotatedLeft = (#(#rest,)* #head,);
+			type RotatedRight = (#tail, #(#init,)*);
+
+			#[inline]
+			fn rotate_left(self) -> Self::RotatedLeft {
+				let (#(#idents,)*) = self;
+				(#(#rest,)* #head,)
+			}
+
+			#[inline]
+			fn rotate_right(self) -> Self::RotatedRight {
+				let (#(#idents,)*) = self;
+				(#tail, #(#init,)*)
+			}
+		}
+	})
+}
+
+pub fn impl_rotate_by(idents: &[Ident], max_arity: usize) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for i in 0..=max_arity {
+		let count = Literal::usize_unsuffixed(i);
+		let (left, right) = idents.split_at(i.checked_rem(idents.len()).unwrap_or(0));
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> RotateByTuple<#count> for (#(#idents,)*) {
+				type Output = (#(#right,)* #(#left,)*);
+
+				#[inline]
+				fn rotate_by(self) -> Self::Output {
+					let (#(#idents,)*) = self;
+					(#(#right,)* #(#left,)*)
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
 pub fn impl_indexable(idents: &[Ident]) -> TokenStream {
 	let mut tokens = TokenStream::new();
 	for (i, ident) in idents.iter().enumerate() {
@@ -228,6 +378,12 @@ pub fn impl_indexable(idents: &[Ident]) -> TokenStream {
 				fn into_index(self) -> Self::Value {
 					self.#index
 				}
+
+				#[inline]
+				fn set(mut self, value: Self::Value) -> Self {
+					self.#index = value;
+					self
+				}
 			}
 		});
 	}
@@ -235,32 +391,990 @@ pub fn impl_indexable(idents: &[Ident]) -> TokenStream {
 	tokens
 }
 
-pub fn impl_fns(idents: &[Ident]) -> TokenStream {
+pub fn impl_replace(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for (i, ident) in idents.iter().enumerate() {
+		let index = Literal::usize_unsuffixed(i);
+		let (left, right) = (&idents[..i], &idents[i + 1..]);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> ReplaceTuple<#index> for (#(#idents,)*) {
+				type Replaced<U> = (#(#left,)* U, #(#right,)*);
+
+				#[inline]
+				fn replace<U>(self, value: U) -> (Self::Value, Self::Replaced<U>) {
+					let (#(#idents,)*) = self;
+					(#ident, (#(#left,)* value, #(#right,)*))
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_map_at(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for (i, ident) in idents.iter().enumerate() {
+		let index = Literal::usize_unsuffixed(i);
+		let (left, right) = (&idents[..i], &idents[i + 1..]);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> MapAtTuple<#index> for (#(#idents,)*) {
+				type Mapped<U> = (#(#left,)* U, #(#right,)*);
+
+				#[inline]
+				fn map_at<U, F: core::ops::FnOnce(Self::Value) -> U>(self, f: F) -> Self::Mapped<U> {
+					let (#(#idents,)*) = self;
+					(#(#left,)* f(#ident), #(#right,)*)
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_insert(idents: &[Ident], max_arity: usize) -> Option<TokenStream> {
+	if idents.len() == max_arity {
+		return None;
+	}
+
+	let mut tokens = TokenStream::new();
+	for i in 0..=idents.len() {
+		let index = Literal::usize_unsuffixed(i);
+		let (left, right) = idents.split_at(i);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> InsertTuple<#index> for (#(#idents,)*) {
+				type Inserted<U> = (#(#left,)* U, #(#right,)*);
+
+				#[inline]
+				fn insert<U>(self, value: U) -> Self::Inserted<U> {
+					let (#(#idents,)*) = self;
+					(#(#left,)* value, #(#right,)*)
+				}
+			}
+		});
+	}
+
+	Some(tokens)
+}
+
+pub fn impl_remove(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for (i, ident) in idents.iter().enumerate() {
+		let index = Literal::usize_unsuffixed(i);
+		let (left, right) = (&idents[..i], &idents[i + 1..]);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> RemoveTuple<#index> for (#(#idents,)*) {
+				type Removed = (#(#left,)* #(#right,)*);
+
+				#[inline]
+				fn remove(self) -> (Self::Value, Self::Removed) {
+					let (#(#idents,)*) = self;
+					(#ident, (#(#left,)* #(#right,)*))
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_swap_remove(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for (i, ident) in idents.iter().enumerate() {
+		let index = Literal::usize_unsuffixed(i);
+		let left = &idents[..i];
+		let right = match &idents[i + 1..] {
+			[] => quote!(),
+			[middle @ .., tail] => quote!(#tail, #(#middle,)*),
+		};
+
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> SwapRemoveTuple<#index> for (#(#idents,)*) {
+				type Removed = (#(#left,)* #right);
+
+				#[inline]
+				fn swap_remove(self) -> (Self::Value, Self::Removed) {
+					let (#(#idents,)*) = self;
+					(#ident, (#(#left,)* #right))
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_eq_ignoring(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for (i, ident) in idents.iter().enumerate() {
+		let index = Literal::usize_unsuffixed(i);
+		let (left, right) = (&idents[..i], &idents[i + 1..]);
+		let compared = (0..idents.len())
+			.filter(|&j| j != i)
+			.map(Literal::usize_unsuffixed);
+
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#left: PartialEq,)* #ident, #(#right: PartialEq,)*> EqIgnoringTuple<#index> for (#(#idents,)*) {
+				#[inline]
+				fn eq_ignoring(&self, other: &Self) -> bool {
+					#(self.#compared == other.#compared &&)* true
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_hash_indices(idents: &[Ident]) -> TokenStream {
+	let indices = (0..idents.len()).map(Literal::usize_unsuffixed);
+
 	quote! {
 		#[automatically_derived]
-		impl<#(#idents,)* F: core::ops::FnOnce(#(#idents,)*) -> Output, Output> FnOnce<(#(#idents,)*)> for F {
-			type Output = Output;
+		impl<#(#idents: core::hash::Hash,)*> HashIndicesTuple for (#(#idents,)*) {
+			#[inline]
+			fn hash_indices<H: core::hash::Hasher>(&self, indices: &[usize], state: &mut H) {
+				for index in indices {
+					match index {
+						#(#indices => self.#indices.hash(state),)*
+						_ => {}
+					}
+				}
+			}
+		}
+	}
+}
+
+pub fn impl_ref(idents: &[Ident]) -> TokenStream {
+	let indices = (0..idents.len())
+		.map(Literal::usize_unsuffixed)
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)*> RefTuple for (#(#idents,)*) {
+			type Refs<'a> = (#(&'a #idents,)*) where Self: 'a;
+			type RefsMut<'a> = (#(&'a mut #idents,)*) where Self: 'a;
 
 			#[inline]
-			fn call_once(self, (#(#idents,)*): (#(#idents,)*)) -> Self::Output {
-				self(#(#idents,)*)
+			fn as_refs<'a>(&'a self) -> Self::Refs<'a> {
+				(#(&self.#indices,)*)
+			}
+
+			#[inline]
+			fn as_muts<'a>(&'a mut self) -> Self::RefsMut<'a> {
+				(#(&mut self.#indices,)*)
 			}
 		}
+	}
+}
+
+pub fn impl_homogeneous(idents: &[Ident]) -> TokenStream {
+	let arity = Literal::usize_unsuffixed(idents.len());
+	let types = idents.iter().map(|_| quote!(T));
+	let mapped = idents.iter().map(|_| quote!(U));
+	let indices = (0..idents.len())
+		.map(Literal::usize_unsuffixed)
+		.collect::<Vec<_>>();
+	let others = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+	let repeated = match idents.len() {
+		0 => quote!(core::mem::drop(value)),
+		len => {
+			let clones = (1..len).map(|_| quote!(value.clone()));
+			quote!((#(#clones,)* value,))
+		}
+	};
 
+	quote! {
 		#[automatically_derived]
-		impl<#(#idents,)* F: core::ops::FnMut(#(#idents,)*) -> Output, Output> FnMut<(#(#idents,)*)> for F {
+		impl<T> HomogeneousTuple<T> for (#(#types,)*) {
+			type Array = [T; #arity];
+			type Mapped<U> = (#(#mapped,)*);
+
 			#[inline]
-			fn call_mut(&mut self, (#(#idents,)*): (#(#idents,)*)) -> Self::Output {
-				self(#(#idents,)*)
+			fn into_array(self) -> Self::Array {
+				let (#(#idents,)*) = self;
+				[#(#idents),*]
+			}
+
+			#[inline]
+			fn from_array(array: Self::Array) -> Self {
+				let [#(#idents),*] = array;
+				(#(#idents,)*)
+			}
+
+			#[inline]
+			fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Option<Self> {
+				let mut iter = iter.into_iter();
+				#(let #idents = iter.next()?;)*
+				Some((#(#idents,)*))
+			}
+
+			#[inline]
+			fn repeat(value: T) -> Self
+			where
+				T: Clone,
+			{
+				#repeated
+			}
+
+			#[inline]
+			fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator where T: 'a {
+				[#(&self.#indices),*].into_iter()
+			}
+
+			#[inline]
+			fn iter_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut T> + ExactSizeIterator where T: 'a {
+				[#(&mut self.#indices),*].into_iter()
+			}
+
+			#[inline]
+			fn get_dyn(&self, index: usize) -> Option<&T> {
+				match index {
+					#(#indices => Some(&self.#indices),)*
+					_ => None,
+				}
+			}
+
+			#[inline]
+			fn get_dyn_mut(&mut self, index: usize) -> Option<&mut T> {
+				match index {
+					#(#indices => Some(&mut self.#indices),)*
+					_ => None,
+				}
+			}
+
+			#[inline]
+			fn contains(&self, value: &T) -> bool
+			where
+				T: PartialEq,
+			{
+				#(self.#indices == *value ||)* false
+			}
+
+			#[inline]
+			fn position(&self, value: &T) -> Option<usize>
+			where
+				T: PartialEq,
+			{
+				#(if self.#indices == *value {
+					return Some(#indices);
+				})*
+				None
+			}
+
+			#[inline]
+			fn fold<B, F: core::ops::FnMut(B, T) -> B>(self, init: B, mut f: F) -> B {
+				let (#(#idents,)*) = self;
+				let acc = init;
+				#(let acc = f(acc, #idents);)*
+				acc
+			}
+
+			#[inline]
+			fn try_fold<B, E, F: core::ops::FnMut(B, T) -> Result<B, E>>(self, init: B, mut f: F) -> Result<B, E> {
+				let (#(#idents,)*) = self;
+				let acc = init;
+				#(let acc = f(acc, #idents)?;)*
+				Ok(acc)
+			}
+
+			#[inline]
+			fn map<U, F: core::ops::FnMut(T) -> U>(self, mut f: F) -> Self::Mapped<U> {
+				let (#(#idents,)*) = self;
+				(#(f(#idents),)*)
+			}
+
+			#[inline]
+			fn try_map<U, E, F: core::ops::FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<Self::Mapped<U>, E> {
+				let (#(#idents,)*) = self;
+				Ok((#(f(#idents)?,)*))
+			}
+
+			#[inline]
+			fn map_indexed<U, F: core::ops::FnMut(usize, T) -> U>(self, mut f: F) -> Self::Mapped<U> {
+				let (#(#idents,)*) = self;
+				(#(f(#indices, #idents),)*)
+			}
+
+			#[inline]
+			fn zip_with<O, U, F: core::ops::FnMut(T, O) -> U>(self, other: Self::Mapped<O>, mut f: F) -> Self::Mapped<U> {
+				let (#(#idents,)*) = self;
+				let (#(#others,)*) = other;
+				(#(f(#idents, #others),)*)
+			}
+
+			#[inline]
+			fn scan<St, U, F: core::ops::FnMut(&mut St, T) -> U>(self, init: St, mut f: F) -> Self::Mapped<U> {
+				let (#(#idents,)*) = self;
+				let mut state = init;
+				(#(f(&mut state, #idents),)*)
 			}
 		}
+	}
+}
 
+pub fn impl_zippable(idents: &[Ident]) -> TokenStream {
+	let others = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+
+	quote! {
 		#[automatically_derived]
-		impl<#(#idents,)* F: core::ops::Fn(#(#idents,)*) -> Output, Output> Fn<(#(#idents,)*)> for F {
+		impl<#(#idents,)* #(#others,)*> ZippableTuple<(#(#others,)*)> for (#(#idents,)*) {
+			type Zipped = (#((#idents, #others),)*);
+
 			#[inline]
-			fn call(&self, (#(#idents,)*): (#(#idents,)*)) -> Self::Output {
-				self(#(#idents,)*)
+			fn zip(self, other: (#(#others,)*)) -> Self::Zipped {
+				let (#(#idents,)*) = self;
+				let (#(#others,)*) = other;
+				(#((#idents, #others),)*)
 			}
 		}
 	}
 }
+
+pub fn impl_zip_shortest(idents: &[Ident], max_arity: usize) -> TokenStream {
+	let arity = Literal::usize_unsuffixed(idents.len());
+	let others = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+
+	let mut tokens = quote! {
+		#[automatically_derived]
+		impl<T, #(#others,)*> ZipShortest<(#(#others,)*)> for T
+		where
+			T: TakeTuple<#arity>,
+			T::Taken: ZippableTuple<(#(#others,)*)>,
+		{
+			type Output = <T::Taken as ZippableTuple<(#(#others,)*)>>::Zipped;
+
+			#[inline]
+			fn zip_shortest(self, other: (#(#others,)*)) -> Self::Output {
+				TakeTuple::<#arity>::take(self).zip(other)
+			}
+		}
+	};
+
+	if idents.len() < max_arity {
+		let next = Literal::usize_unsuffixed(idents.len() + 1);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)* U> ZipShortest<U> for (#(#idents,)*)
+			where
+				U: TakeTuple<#arity> + TakeTuple<#next>,
+				Self: ZippableTuple<<U as TakeTuple<#arity>>::Taken>,
+			{
+				type Output = <Self as ZippableTuple<<U as TakeTuple<#arity>>::Taken>>::Zipped;
+
+				#[inline]
+				fn zip_shortest(self, other: U) -> Self::Output {
+					self.zip(TakeTuple::<#arity>::take(other))
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_zip_append(idents: &[Ident]) -> TokenStream {
+	let others = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents: GrowableTuple,)* #(#others,)*> ZipAppendTuple<(#(#others,)*)> for (#(#idents,)*) {
+			type Output = (#(#idents::Append<#others>,)*);
+
+			#[inline]
+			fn zip_append(self, other: (#(#others,)*)) -> Self::Output {
+				let (#(#idents,)*) = self;
+				let (#(#others,)*) = other;
+				(#(#idents.append(#others),)*)
+			}
+		}
+	}
+}
+
+pub fn impl_interleave(idents: &[Ident], max_arity: usize) -> Option<TokenStream> {
+	if idents.len() * 2 > max_arity {
+		return None;
+	}
+
+	let others = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+
+	Some(quote! {
+		#[automatically_derived]
+		impl<#(#idents,)* #(#others,)*> InterleaveTuple<(#(#others,)*)> for (#(#idents,)*) {
+			type Output = (#(#idents, #others,)*);
+
+			#[inline]
+			fn interleave(self, other: (#(#others,)*)) -> Self::Output {
+				let (#(#idents,)*) = self;
+				let (#(#others,)*) = other;
+				(#(#idents, #others,)*)
+			}
+		}
+	})
+}
+
+pub fn impl_unzippable(idents: &[Ident]) -> TokenStream {
+	let others = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)* #(#others,)*> UnzippableTuple for (#((#idents, #others),)*) {
+			type Lefts = (#(#idents,)*);
+			type Rights = (#(#others,)*);
+
+			#[inline]
+			fn unzip(self) -> (Self::Lefts, Self::Rights) {
+				let (#((#idents, #others),)*) = self;
+				((#(#idents,)*), (#(#others,)*))
+			}
+		}
+	}
+}
+
+pub fn impl_split(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for i in 0..=idents.len() {
+		let index = Literal::usize_unsuffixed(i);
+		let (left, right) = idents.split_at(i);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> SplitTuple<#index> for (#(#idents,)*) {
+				type Left = (#(#left,)*);
+				type Right = (#(#right,)*);
+
+				#[inline]
+				fn split_at(self) -> (Self::Left, Self::Right) {
+					let (#(#idents,)*) = self;
+					((#(#left,)*), (#(#right,)*))
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_take(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for i in 0..=idents.len() {
+		let count = Literal::usize_unsuffixed(i);
+		let taken = &idents[..i];
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> TakeTuple<#count> for (#(#idents,)*) {
+				type Taken = (#(#taken,)*);
+
+				#[inline]
+				fn take(self) -> Self::Taken {
+					let (#(#taken,)* ..) = self;
+					(#(#taken,)*)
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_skip(idents: &[Ident]) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for i in 0..=idents.len() {
+		let count = Literal::usize_unsuffixed(i);
+		let skipped = &idents[i..];
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> SkipTuple<#count> for (#(#idents,)*) {
+				type Skipped = (#(#skipped,)*);
+
+				#[inline]
+				fn skip(self) -> Self::Skipped {
+					let (.., #(#skipped,)*) = self;
+					(#(#skipped,)*)
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_windows(idents: &[Ident], max_arity: usize) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for size in 1..=max_arity {
+		let count = (idents.len() + 1).saturating_sub(size);
+		let windows = (0..count).map(|i| {
+			let types = &idents[i..i + size];
+			let values = (i..i + size).map(|j| {
+				let ident = &idents[j];
+				if i == j.min(count - 1) {
+					quote!(#ident)
+				} else {
+					quote!(#ident.clone())
+				}
+			});
+
+			(quote!((#(#types,)*)), quote!((#(#values,)*)))
+		});
+
+		let (types, values): (Vec<_>, Vec<_>) = windows.unzip();
+		let bounds = (0..idents.len()).map(|j| {
+			let ident = &idents[j];
+			if count > 0 && j.saturating_sub(size - 1) < j.min(count - 1) {
+				quote!(#ident: Clone)
+			} else {
+				quote!(#ident)
+			}
+		});
+
+		let size = Literal::usize_unsuffixed(size);
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#bounds,)*> WindowsTuple<#size> for (#(#idents,)*) {
+				type Output = (#(#types,)*);
+
+				#[inline]
+				fn windows(self) -> Self::Output {
+					let (#(#idents,)*) = self;
+					(#(#values,)*)
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_chunks(idents: &[Ident], max_arity: usize) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for size in (1..=max_arity).filter(|size| idents.len() % size == 0) {
+		let count = Literal::usize_unsuffixed(size);
+		let chunks = idents.chunks(size).map(|chunk| quote!((#(#chunk,)*)));
+		let output = chunks.clone();
+		tokens.extend(quote! {
+			#[automatically_derived]
+			impl<#(#idents,)*> ChunksTuple<#count> for (#(#idents,)*) {
+				type Output = (#(#output,)*);
+
+				#[inline]
+				fn chunks(self) -> Self::Output {
+					let (#(#idents,)*) = self;
+					(#(#chunks,)*)
+				}
+			}
+		});
+	}
+
+	tokens
+}
+
+pub fn impl_swappable(max_arity: usize) -> TokenStream {
+	let mut tokens = TokenStream::new();
+	for i in 0..max_arity {
+		for j in 0..max_arity {
+			let (first, second) = (Literal::usize_unsuffixed(i), Literal::usize_unsuffixed(j));
+			tokens.extend(if i == j {
+				quote! {
+					#[automatically_derived]
+					impl<T: IndexableTuple<#first>> SwappableTuple<#first, #first> for T {
+						#[inline]
+						fn swap(self) -> Self {
+							self
+						}
+					}
+				}
+			} else {
+				quote! {
+					#[automatically_derived]
+					impl<T, V> SwappableTuple<#first, #second> for T
+					where
+						T: IndexableTuple<#second, Value = V> + ReplaceTuple<#first, Value = V>,
+						<T as ReplaceTuple<#first>>::Replaced<()>: ReplaceTuple<#second, Value = V>,
+						<<T as ReplaceTuple<#first>>::Replaced<()> as ReplaceTuple<#second>>::Replaced<V>: ReplaceTuple<#first, Value = (), Replaced<V> = T>,
+					{
+						#[inline]
+						fn swap(self) -> Self {
+							let (first, tuple) = ReplaceTuple::<#first>::replace(self, ());
+							let (second, tuple) = ReplaceTuple::<#second>::replace(tuple, first);
+							let ((), tuple) = ReplaceTuple::<#first>::replace(tuple, second);
+							tuple
+						}
+					}
+				}
+			});
+		}
+	}
+
+	tokens
+}
+
+pub fn impl_flatten(idents: &[Ident]) -> TokenStream {
+	let mut join = quote!(());
+	let mut bounds = Vec::new();
+	for ident in idents {
+		bounds.push(quote!(#join: JoinableTuple<#ident>));
+		bounds.push(quote!(#ident: JoinableTuple<#join>));
+		join = quote!(<#join as JoinableTuple<#ident>>::Join);
+	}
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)*> FlattenTuple for (#(#idents,)*) where #(#bounds,)* {
+			type Flattened = #join;
+
+			#[inline]
+			fn flatten(self) -> Self::Flattened {
+				let (#(#idents,)*) = self;
+				let tuple = ();
+				#(let tuple = JoinableTuple::join(tuple, #idents);)*
+				tuple
+			}
+		}
+	}
+}
+
+pub fn impl_concat_arrays(idents: &[Ident]) -> TokenStream {
+	let lengths = (1..=idents.len())
+		.map(|i| format_ident!("N{i}"))
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[cfg(feature = "alloc")]
+		#[automatically_derived]
+		impl<T, #(const #lengths: usize,)*> ConcatArraysTuple<T> for (#([T; #lengths],)*) {
+			#[inline]
+			fn concat_arrays(self) -> alloc::vec::Vec<T> {
+				let (#(#idents,)*) = self;
+				let mut vec = alloc::vec::Vec::with_capacity(0 #(+ #lengths)*);
+				#(vec.extend(#idents);)*
+				vec
+			}
+		}
+	}
+}
+
+pub fn impl_nested(idents: &[Ident]) -> TokenStream {
+	let indices = (0..idents.len()).map(Literal::usize_unsuffixed);
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents: DynTuple,)*> NestedTuple for (#(#idents,)*) {
+			#[inline]
+			fn nested_arities(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator {
+				[#(self.#indices.arity()),*].into_iter()
+			}
+		}
+	}
+}
+
+pub fn impl_transpose_option(idents: &[Ident]) -> TokenStream {
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)*> TransposeOption for (#(Option<#idents>,)*) {
+			type Output = (#(#idents,)*);
+
+			#[inline]
+			fn transpose(self) -> Option<Self::Output> {
+				let (#(#idents,)*) = self;
+				Some((#(#idents?,)*))
+			}
+		}
+	}
+}
+
+pub fn impl_transpose_result(idents: &[Ident]) -> TokenStream {
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)* E> TransposeResult<E> for (#(Result<#idents, E>,)*) {
+			type Output = (#(#idents,)*);
+
+			#[inline]
+			fn transpose(self) -> Result<Self::Output, E> {
+				let (#(#idents,)*) = self;
+				Ok((#(#idents?,)*))
+			}
+		}
+	}
+}
+
+pub fn impl_as_ref(idents: &[Ident]) -> TokenStream {
+	let arity = Literal::usize_unsuffixed(idents.len());
+	let indices = (0..idents.len()).map(Literal::usize_unsuffixed);
+
+	quote! {
+		#[automatically_derived]
+		impl<'a, U: ?Sized + 'a, #(#idents: AsRef<U> + 'a,)*> TupleAsRef<'a, U> for (#(#idents,)*) {
+			type Iterator = core::array::IntoIter<&'a U, #arity>;
+
+			#[inline]
+			fn tuple_as_ref(&'a self) -> Self::Iterator {
+				[#(self.#indices.as_ref()),*].into_iter()
+			}
+		}
+	}
+}
+
+pub fn impl_map_into(idents: &[Ident]) -> TokenStream {
+	let outputs = idents.iter().map(|_| quote!(U));
+	let reversed = idents.iter().rev();
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents: Into<U>,)* U> MapIntoTuple<U> for (#(#idents,)*) {
+			type Output = (#(#outputs,)*);
+
+			#[inline]
+			fn map_into(self) -> Self::Output {
+				let (#(#idents,)*) = self;
+				(#(#idents.into(),)*)
+			}
+
+			#[inline]
+			fn rev_tuple_into(self) -> impl DoubleEndedIterator<Item = U> + ExactSizeIterator {
+				let (#(#idents,)*) = self;
+				[#(#reversed.into()),*].into_iter()
+			}
+		}
+	}
+}
+
+pub fn impl_default(idents: &[Ident]) -> TokenStream {
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents: Default,)*> DefaultTuple for (#(#idents,)*) {
+			#[inline]
+			fn default_tuple() -> Self {
+				(#(#idents::default(),)*)
+			}
+		}
+	}
+}
+
+pub fn impl_map_each(idents: &[Ident]) -> TokenStream {
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)*> MapTuple for (#(#idents,)*) {
+			type Mapped<M: TupleMapper> = (#(M::Output<#idents>,)*);
+
+			#[inline]
+			fn map_each<M: TupleMapper>(self, mut mapper: M) -> Self::Mapped<M> {
+				let (#(#idents,)*) = self;
+				(#(mapper.map(#idents),)*)
+			}
+		}
+	}
+}
+
+pub fn impl_fold_each(idents: &[Ident]) -> TokenStream {
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)* Acc, F: #(TupleFolder<Acc, #idents> +)*> FoldTuple<Acc, F> for (#(#idents,)*) {
+			#[inline]
+			fn fold_each(self, init: Acc, mut folder: F) -> Acc {
+				let (#(#idents,)*) = self;
+				let acc = init;
+				#(let acc = folder.fold(acc, #idents);)*
+				acc
+			}
+		}
+	}
+}
+
+pub fn impl_enumerate(idents: &[Ident]) -> TokenStream {
+	let indices = (0..idents.len())
+		.map(Literal::usize_unsuffixed)
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)*> EnumerateTuple for (#(#idents,)*) {
+			type Output = (#((usize, #idents),)*);
+
+			#[inline]
+			fn enumerate(self) -> Self::Output {
+				let (#(#idents,)*) = self;
+				(#((#indices, #idents),)*)
+			}
+		}
+	}
+}
+
+pub fn impl_join_futures(idents: &[Ident]) -> TokenStream {
+	let indices = (0..idents.len())
+		.map(Literal::usize_unsuffixed)
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[cfg(feature = "futures")]
+		#[automatically_derived]
+		impl<#(#idents: core::future::IntoFuture,)*> fns::join::JoinFutures for (#(#idents,)*) {
+			type Output = (#(#idents::Output,)*);
+
+			#[inline]
+			fn join_all(self) -> impl core::future::Future<Output = Self::Output> {
+				let (#(#idents,)*) = self;
+				async move {
+					#(let mut #idents = core::pin::pin!(core::future::IntoFuture::into_future(#idents));)*
+					let mut outputs = (#(None::<#idents::Output>,)*);
+					core::future::poll_fn(move |cx| {
+						let mut ready = true;
+						#(
+							if outputs.#indices.is_none() {
+								match core::future::Future::poll(#idents.as_mut(), cx) {
+									core::task::Poll::Ready(output) => outputs.#indices = Some(output),
+									core::task::Poll::Pending => ready = false,
+								}
+							}
+						)*
+
+						if ready {
+							core::task::Poll::Ready((#(outputs.#indices.take().unwrap(),)*))
+						} else {
+							core::task::Poll::Pending
+						}
+					})
+					.await
+				}
+			}
+		}
+	}
+}
+
+pub fn impl_try_join_futures(idents: &[Ident]) -> TokenStream {
+	let indices = (0..idents.len())
+		.map(Literal::usize_unsuffixed)
+		.collect::<Vec<_>>();
+	let oks = (1..=idents.len())
+		.map(|i| format_ident!("U{i}"))
+		.collect::<Vec<_>>();
+
+	quote! {
+		#[cfg(feature = "futures")]
+		#[automatically_derived]
+		impl<#(#idents: core::future::IntoFuture<Output = Result<#oks, E>>,)* #(#oks,)* E> fns::join::TryJoinFutures<E> for (#(#idents,)*) {
+			type Output = (#(#oks,)*);
+
+			#[inline]
+			fn try_join_all(self) -> impl core::future::Future<Output = Result<Self::Output, E>> {
+				let (#(#idents,)*) = self;
+				async move {
+					#(let mut #idents = core::pin::pin!(core::future::IntoFuture::into_future(#idents));)*
+					let mut outputs = (#(None::<#oks>,)*);
+					core::future::poll_fn(move |cx| {
+						let mut ready = true;
+						#(
+							if outputs.#indices.is_none() {
+								match core::future::Future::poll(#idents.as_mut(), cx) {
+									core::task::Poll::Ready(Ok(output)) => outputs.#indices = Some(output),
+									core::task::Poll::Ready(Err(err)) => return core::task::Poll::Ready(Err(err)),
+									core::task::Poll::Pending => ready = false,
+								}
+							}
+						)*
+
+						if ready {
+							core::task::Poll::Ready(Ok((#(outputs.#indices.take().unwrap(),)*)))
+						} else {
+							core::task::Poll::Pending
+						}
+					})
+					.await
+				}
+			}
+		}
+	}
+}
+
+pub fn impl_fns(idents: &[Ident]) -> TokenStream {
+	quote! {
+		#[automatically_derived]
+		impl<#(#idents,)* F: core::ops::FnOnce(#(#idents,)*) -> Output, Output> FnOnce<(#(#idents,)*)> for F {
+			type Output = Output;
+
+			#[inline]
+			fn call_once(self, (#(#idents,)*): (#(#idents,)*)) -> Self::Output {
+				self(#(#idents,)*)
+			}
+		}
+
+		#[automatically_derived]
+		impl<#(#idents,)* F: core::ops::FnMut(#(#idents,)*) -> Output, Output> FnMut<(#(#idents,)*)> for F {
+			#[inline]
+			fn call_mut(&mut self, (#(#idents,)*): (#(#idents,)*)) -> Self::Output {
+				self(#(#idents,)*)
+			}
+		}
+
+		#[automatically_derived]
+		impl<#(#idents,)* F: core::ops::Fn(#(#idents,)*) -> Output, Output> Fn<(#(#idents,)*)> for F {
+			#[inline]
+			fn call(&self, (#(#idents,)*): (#(#idents,)*)) -> Self::Output {
+				self(#(#idents,)*)
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::BTreeSet;
+
+	/// Returns the names of the traits implemented by the top-level items of `tokens`.
+	fn implemented_traits(tokens: TokenStream) -> BTreeSet<String> {
+		let mut traits = BTreeSet::new();
+		let mut tokens = tokens.into_iter();
+		while let Some(token) = tokens.next() {
+			if !matches!(&token, TokenTree::Ident(ident) if ident == "impl") {
+				continue;
+			}
+
+			let mut depth = 0;
+			let mut arrow = false;
+			let mut name = None;
+			for token in tokens.by_ref() {
+				match &token {
+					TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+					TokenTree::Punct(punct) if punct.as_char() == '>' && !arrow => depth -= 1,
+					TokenTree::Ident(ident) if depth == 0 && ident == "for" => break,
+					TokenTree::Ident(ident) if depth == 0 => name = Some(ident.to_string()),
+					_ => {}
+				}
+				arrow = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '-');
+			}
+
+			traits.extend(name);
+		}
+
+		traits
+	}
+
+	#[test]
+	fn same_traits_for_every_arity_limit() {
+		let traits = implemented_traits(impl_all_traits(8, 8));
+		assert!(traits.contains("Tuple"));
+		assert!(traits.contains("SwappableTuple"));
+		for (max_arity, max_const_arity) in [(16, 16), (32, 32), (64, 32)] {
+			assert_eq!(traits, implemented_traits(impl_all_traits(max_arity, max_const_arity)));
+		}
+	}
+}
@@ -1,18 +1,44 @@
 use super::*;
 use core::future::{Future, IntoFuture};
 
+/// Partial application of functions.
+pub mod bind;
+
+/// Functions that return a value implementing [`Clone`].
+pub mod clone;
+
+/// Composition of functions.
+pub mod compose;
+
+/// Currying and uncurrying of functions.
+pub mod curry;
+
+/// Functions that return a value implementing [`Default`].
+pub mod default;
+
 /// Functions that return a [`Future`].
 pub mod future;
 
+/// Concurrently awaiting tuples of futures.
+#[cfg(feature = "futures")]
+pub mod join;
+
 /// Functions that return an [`Iterator`].
 pub mod iter;
 
+/// Mapping the output of functions.
+pub mod map;
+
 /// Functions that return an [`Option`].
 pub mod option;
 
 /// Functions that return a [`Result`].
 pub mod result;
 
+/// Functions that return a [`Vec`](alloc::vec::Vec).
+#[cfg(feature = "alloc")]
+pub mod vec;
+
 /// Functions that are called by value.
 pub trait FnOnce<T: Tuple> {
 	/// The return type of this function.
@@ -33,3 +59,138 @@ pub trait Fn<T: Tuple>: FnMut<T> {
 	/// Call the function by reference.
 	fn call(&self, args: T) -> Self::Output;
 }
+
+/// Tuples that can be used as the arguments of a function.
+pub trait TupleApply: Tuple {
+	/// Calls a function by value, using this tuple as its arguments.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::fns::TupleApply;
+	/// fn add3(a: i32, b: i32, c: i32) -> i32 {
+	///     a + b + c
+	/// }
+	///
+	/// assert_eq!(6, (1, 2, 3).apply(add3));
+	/// ```
+	#[inline]
+	fn apply<F: FnOnce<Self>>(self, f: F) -> F::Output {
+		f.call_once(self)
+	}
+
+	/// Calls a function by mutable reference, using this tuple as its arguments.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::fns::TupleApply;
+	/// let mut total = 0;
+	/// let mut add = |a: i32, b: i32| total += a + b;
+	/// (1, 2).apply_mut(&mut add);
+	/// (3, 4).apply_mut(&mut add);
+	/// assert_eq!(10, total);
+	/// ```
+	#[inline]
+	fn apply_mut<F: FnMut<Self>>(self, f: &mut F) -> F::Output {
+		f.call_mut(self)
+	}
+
+	/// Calls a function by reference, using this tuple as its arguments.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::fns::TupleApply;
+	/// let add = |a: i32, b: i32| a + b;
+	/// assert_eq!(3, (1, 2).apply_ref(&add));
+	/// assert_eq!(7, (3, 4).apply_ref(&add));
+	/// ```
+	#[inline]
+	fn apply_ref<F: Fn<Self>>(self, f: &F) -> F::Output {
+		f.call(self)
+	}
+
+	/// Calls a function by value, using references to the elements of this tuple as its arguments, without consuming this tuple.
+	///
+	/// The function must take a reference to each element, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::fns::TupleApply;
+	/// fn describe(name: &String, age: &u32) -> String {
+	///     format!("{name} is {age}")
+	/// }
+	///
+	/// let tuple = (String::from("Alice"), 30);
+	/// assert_eq!("Alice is 30", tuple.apply_refs(describe));
+	/// assert_eq!(5, tuple.apply_refs(|name: &String, _: &u32| name.len()));
+	/// assert_eq!((String::from("Alice"), 30), tuple);
+	/// ```
+	#[inline]
+	fn apply_refs<'a, F: FnOnce<Self::Refs<'a>>>(&'a self, f: F) -> F::Output
+	where
+		Self: RefTuple,
+	{
+		f.call_once(self.as_refs())
+	}
+
+	/// Calls a function returning a [`Future`] by value, using this tuple as its arguments, and returns that [`Future`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use futures::executor::block_on;
+	/// # use tupl::fns::TupleApply;
+	/// async fn add(a: i32, b: i32) -> i32 {
+	///     a + b
+	/// }
+	///
+	/// assert_eq!(3, block_on((1, 2).apply_async(add)));
+	/// ```
+	#[inline]
+	fn apply_async<F: future::FnFutureOnce<Self>>(self, f: F) -> F::Future {
+		f.call_once(self).into_future()
+	}
+
+	/// Calls a function returning a [`Future`] by mutable reference, using this tuple as its arguments, and returns that [`Future`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use futures::executor::block_on;
+	/// # use tupl::fns::TupleApply;
+	/// let mut calls = 0;
+	/// let mut add = |a: i32, b: i32| {
+	///     calls += 1;
+	///     async move { a + b }
+	/// };
+	///
+	/// assert_eq!(3, block_on((1, 2).apply_async_mut(&mut add)));
+	/// assert_eq!(7, block_on((3, 4).apply_async_mut(&mut add)));
+	/// assert_eq!(2, calls);
+	/// ```
+	#[inline]
+	fn apply_async_mut<F: future::FnFutureMut<Self>>(self, f: &mut F) -> F::Future {
+		f.call_mut(self).into_future()
+	}
+
+	/// Calls a function returning a [`Future`] by reference, using this tuple as its arguments, and returns that [`Future`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use futures::executor::block_on;
+	/// # use tupl::fns::TupleApply;
+	/// let add = |a: i32, b: i32| async move { a + b };
+	/// assert_eq!(3, block_on((1, 2).apply_async_ref(&add)));
+	/// assert_eq!(7, block_on((3, 4).apply_async_ref(&add)));
+	/// ```
+	#[inline]
+	fn apply_async_ref<F: future::FnFuture<Self>>(self, f: &F) -> F::Future {
+		f.call(self).into_future()
+	}
+}
+
+impl<T: Tuple> TupleApply for T {}
@@ -0,0 +1,102 @@
+use super::*;
+
+/// A function with its first argument bound to a value, created using [`bind_head`].
+///
+/// Calling it prepends the bound value to the given arguments before calling the function.
+pub struct BoundHead<F, U> {
+	f: F,
+	head: U,
+}
+
+/// Binds the first argument of a function to a value, returning a function that takes the remaining arguments.
+///
+/// Calling the returned function by (mutable) reference clones the bound value.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{Fn, FnOnce, bind::bind_head};
+/// let sub = |a: i32, b: i32| a - b;
+/// let ten_minus = bind_head(sub, 10);
+/// assert_eq!(7, ten_minus.call((3,)));
+///
+/// let describe = |name: &str, age: u32, city: &str| format!("{name}, {age}, {city}");
+/// let alice = bind_head(describe, "Alice");
+/// assert_eq!("Alice, 30, Paris", alice.call_once((30, "Paris")));
+/// ```
+#[inline]
+pub fn bind_head<F, U>(f: F, head: U) -> BoundHead<F, U> {
+	BoundHead { f, head }
+}
+
+impl<T: GrowableTuple, F: FnOnce<T::Prepend<U>>, U> FnOnce<T> for BoundHead<F, U> {
+	type Output = F::Output;
+
+	#[inline]
+	fn call_once(self, args: T) -> Self::Output {
+		self.f.call_once(args.prepend(self.head))
+	}
+}
+
+impl<T: GrowableTuple, F: FnMut<T::Prepend<U>>, U: Clone> FnMut<T> for BoundHead<F, U> {
+	#[inline]
+	fn call_mut(&mut self, args: T) -> Self::Output {
+		self.f.call_mut(args.prepend(self.head.clone()))
+	}
+}
+
+impl<T: GrowableTuple, F: Fn<T::Prepend<U>>, U: Clone> Fn<T> for BoundHead<F, U> {
+	#[inline]
+	fn call(&self, args: T) -> Self::Output {
+		self.f.call(args.prepend(self.head.clone()))
+	}
+}
+
+/// A function with its last argument bound to a value, created using [`bind_tail`].
+///
+/// Calling it appends the bound value to the given arguments before calling the function.
+pub struct BoundTail<F, U> {
+	f: F,
+	tail: U,
+}
+
+/// Binds the last argument of a function to a value, returning a function that takes the remaining arguments.
+///
+/// Calling the returned function by (mutable) reference clones the bound value.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{Fn, bind::bind_tail};
+/// let clamp = |value: i32, min: i32, max: i32| value.max(min).min(max);
+/// let clamp_to_ten = bind_tail(clamp, 10);
+/// assert_eq!(clamp.call((15, 0, 10)), clamp_to_ten.call((15, 0)));
+/// assert_eq!(clamp.call((-5, 0, 10)), clamp_to_ten.call((-5, 0)));
+/// ```
+#[inline]
+pub fn bind_tail<F, U>(f: F, tail: U) -> BoundTail<F, U> {
+	BoundTail { f, tail }
+}
+
+impl<T: GrowableTuple, F: FnOnce<T::Append<U>>, U> FnOnce<T> for BoundTail<F, U> {
+	type Output = F::Output;
+
+	#[inline]
+	fn call_once(self, args: T) -> Self::Output {
+		self.f.call_once(args.append(self.tail))
+	}
+}
+
+impl<T: GrowableTuple, F: FnMut<T::Append<U>>, U: Clone> FnMut<T> for BoundTail<F, U> {
+	#[inline]
+	fn call_mut(&mut self, args: T) -> Self::Output {
+		self.f.call_mut(args.append(self.tail.clone()))
+	}
+}
+
+impl<T: GrowableTuple, F: Fn<T::Append<U>>, U: Clone> Fn<T> for BoundTail<F, U> {
+	#[inline]
+	fn call(&self, args: T) -> Self::Output {
+		self.f.call(args.append(self.tail.clone()))
+	}
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Functions that are called by value and return a value implementing [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{FnOnce, clone::FnCloneOnce};
+/// fn call_twice<F: FnCloneOnce<(i32,)>>(f: F, arg: i32) -> (F::Output, F::Output) {
+///     let output = f.call_once((arg,));
+///     (output.clone(), output)
+/// }
+///
+/// let to_string = |value: i32| value.to_string();
+/// assert_eq!((String::from("1"), String::from("1")), call_twice(to_string, 1));
+/// ```
+pub trait FnCloneOnce<T: Tuple>: FnOnce<T, Output: Clone> {}
+impl<T: Tuple, F: FnOnce<T, Output: Clone>> FnCloneOnce<T> for F {}
+
+/// Functions that can be called by mutable reference and return a value implementing [`Clone`].
+pub trait FnCloneMut<T: Tuple>: FnMut<T> + FnCloneOnce<T> {}
+impl<T: Tuple, F: FnMut<T> + FnCloneOnce<T>> FnCloneMut<T> for F {}
+
+/// Functions that can be called by reference and return a value implementing [`Clone`].
+pub trait FnClone<T: Tuple>: Fn<T> + FnCloneMut<T> {}
+impl<T: Tuple, F: Fn<T> + FnCloneMut<T>> FnClone<T> for F {}
+
+/// Functions that are called by value and return a value that can be turned into a [`Future`] whose output implements [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// # use futures::executor::block_on;
+/// # use tupl::fns::{FnOnce, clone::FnFutureCloneOnce};
+/// async fn call_twice<F: FnFutureCloneOnce<(i32,)>>(f: F, arg: i32) -> (F::FutOutput, F::FutOutput) {
+///     let output = f.call_once((arg,)).await;
+///     (output.clone(), output)
+/// }
+///
+/// let to_string = |value: i32| async move { value.to_string() };
+/// assert_eq!((String::from("1"), String::from("1")), block_on(call_twice(to_string, 1)));
+/// ```
+pub trait FnFutureCloneOnce<T: Tuple>: future::FnFutureOnce<T, FutOutput: Clone> {}
+impl<T: Tuple, F: future::FnFutureOnce<T, FutOutput: Clone>> FnFutureCloneOnce<T> for F {}
+
+/// Functions that can be called by mutable reference and return a value that can be turned into a [`Future`] whose output implements [`Clone`].
+pub trait FnFutureCloneMut<T: Tuple>: FnMut<T> + FnFutureCloneOnce<T> {}
+impl<T: Tuple, F: FnMut<T> + FnFutureCloneOnce<T>> FnFutureCloneMut<T> for F {}
+
+/// Functions that can be called by reference and return a value that can be turned into a [`Future`] whose output implements [`Clone`].
+pub trait FnFutureClone<T: Tuple>: Fn<T> + FnFutureCloneMut<T> {}
+impl<T: Tuple, F: Fn<T> + FnFutureCloneMut<T>> FnFutureClone<T> for F {}
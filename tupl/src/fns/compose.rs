@@ -0,0 +1,52 @@
+use super::*;
+
+/// Two functions composed together, created using [`compose()`].
+///
+/// Calling it calls the first function, then calls the second one using the output of the first one as its arguments.
+pub struct Composed<F, G> {
+	f: F,
+	g: G,
+}
+
+/// Composes two functions together, the output of the first one being used as the arguments of the second one.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{Fn, compose::compose};
+/// let div_rem = |a: i32, b: i32| (a / b, a % b);
+/// let describe = |div: i32, rem: i32| format!("{div} rem {rem}");
+/// let composed = compose(div_rem, describe);
+/// assert_eq!("3 rem 1", composed.call((7, 2)));
+///
+/// let log = || ();
+/// let answer = || 42;
+/// assert_eq!(42, compose(log, answer).call(()));
+/// ```
+#[inline]
+pub fn compose<F, G>(f: F, g: G) -> Composed<F, G> {
+	Composed { f, g }
+}
+
+impl<T: Tuple, F: FnOnce<T, Output: Tuple>, G: FnOnce<F::Output>> FnOnce<T> for Composed<F, G> {
+	type Output = G::Output;
+
+	#[inline]
+	fn call_once(self, args: T) -> Self::Output {
+		self.g.call_once(self.f.call_once(args))
+	}
+}
+
+impl<T: Tuple, F: FnMut<T, Output: Tuple>, G: FnMut<F::Output>> FnMut<T> for Composed<F, G> {
+	#[inline]
+	fn call_mut(&mut self, args: T) -> Self::Output {
+		self.g.call_mut(self.f.call_mut(args))
+	}
+}
+
+impl<T: Tuple, F: Fn<T, Output: Tuple>, G: Fn<F::Output>> Fn<T> for Composed<F, G> {
+	#[inline]
+	fn call(&self, args: T) -> Self::Output {
+		self.g.call(self.f.call(args))
+	}
+}
@@ -0,0 +1,140 @@
+use super::*;
+use core::marker::PhantomData;
+
+/// A curried function, created using [`curry()`].
+///
+/// It takes its arguments one at a time: calling it with the next argument returns another [`Curried`]
+/// function waiting for the remaining arguments, or the output of the original function once all of them have been provided.
+///
+/// Since the standard function traits can't be implemented on stable Rust, it is called using [`FnOnce::call_once`].
+pub struct Curried<F, Args, Rest> {
+	f: F,
+	args: Args,
+	rest: PhantomData<fn(Rest)>,
+}
+
+/// Curries a function, turning a function that takes `N` arguments into a function that takes `N` times a single argument.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{FnOnce, curry::curry};
+/// let add = curry(|a: i32, b: i32| a + b);
+/// assert_eq!(3, add.call_once((1,)).call_once((2,)));
+///
+/// let concat = curry(|a: i32, b: &str, c: char| format!("{a}{b}{c}"));
+/// let concat = concat.call_once((1,)).call_once(("b",));
+/// assert_eq!("1bc", concat.call_once(('c',)));
+/// ```
+#[inline]
+pub fn curry<T: NonEmptyTuple, F: FnOnce<T>>(f: F) -> Curried<F, (), T> {
+	Curried {
+		f,
+		args: (),
+		rest: PhantomData,
+	}
+}
+
+/// The arguments a [`Curried`] function is still waiting for.
+pub trait CurriedArgs<F, Args>: NonEmptyTuple {
+	/// The output of the [`Curried`] function once called with the next argument.
+	type Output;
+
+	/// Calls the [`Curried`] function with the next argument.
+	fn call_next(f: F, args: Args, next: Self::Head) -> Self::Output;
+}
+
+impl<F: FnOnce<Args::Append<A>>, Args: GrowableTuple, A> CurriedArgs<F, Args> for (A,) {
+	type Output = F::Output;
+
+	#[inline]
+	fn call_next(f: F, args: Args, next: A) -> Self::Output {
+		f.call_once(args.append(next))
+	}
+}
+
+impl<F, Args: GrowableTuple, Rest: NonUnaryTuple> CurriedArgs<F, Args> for Rest {
+	type Output = Curried<F, Args::Append<Rest::Head>, Rest::TruncateHead>;
+
+	#[inline]
+	fn call_next(f: F, args: Args, next: Rest::Head) -> Self::Output {
+		Curried {
+			f,
+			args: args.append(next),
+			rest: PhantomData,
+		}
+	}
+}
+
+impl<F, Args, Rest: CurriedArgs<F, Args>> FnOnce<(Rest::Head,)> for Curried<F, Args, Rest> {
+	type Output = Rest::Output;
+
+	#[inline]
+	fn call_once(self, (next,): (Rest::Head,)) -> Self::Output {
+		Rest::call_next(self.f, self.args, next)
+	}
+}
+
+/// An uncurried function, created using [`uncurry`].
+///
+/// It takes all of its arguments at once and passes them one at a time to the original function.
+pub struct Uncurried<F> {
+	f: F,
+}
+
+/// Uncurries a function, turning a function that takes `N` times a single argument into a function that takes `N` arguments.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{FnOnce, curry::{curry, uncurry}};
+/// let add = uncurry(|a: i32| move |b: i32| move |c: i32| a + b + c);
+/// assert_eq!(6, add.call_once((1, 2, 3)));
+///
+/// let concat = |a: i32, b: &str, c: char| format!("{a}{b}{c}");
+/// let uncurried = uncurry(curry(concat));
+/// assert_eq!(concat(1, "b", 'c'), uncurried.call_once((1, "b", 'c')));
+/// ```
+#[inline]
+pub fn uncurry<F>(f: F) -> Uncurried<F> {
+	Uncurried { f }
+}
+
+/// The arguments an [`Uncurried`] function can be called with.
+pub trait UncurriedArgs<F>: NonEmptyTuple {
+	/// The output of the [`Uncurried`] function.
+	type Output;
+
+	/// Calls the original function with each argument, one at a time.
+	fn call_each(f: F, args: Self) -> Self::Output;
+}
+
+impl<F: FnOnce<(A,)>, A> UncurriedArgs<F> for (A,) {
+	type Output = F::Output;
+
+	#[inline]
+	fn call_each(f: F, args: Self) -> Self::Output {
+		f.call_once(args)
+	}
+}
+
+impl<F: FnOnce<(T::Head,)>, T: NonUnaryTuple<TruncateHead: UncurriedArgs<F::Output>>>
+	UncurriedArgs<F> for T
+{
+	type Output = <T::TruncateHead as UncurriedArgs<F::Output>>::Output;
+
+	#[inline]
+	fn call_each(f: F, args: Self) -> Self::Output {
+		let (head, rest) = args.truncate_head();
+		UncurriedArgs::call_each(f.call_once((head,)), rest)
+	}
+}
+
+impl<F, T: UncurriedArgs<F>> FnOnce<T> for Uncurried<F> {
+	type Output = T::Output;
+
+	#[inline]
+	fn call_once(self, args: T) -> Self::Output {
+		T::call_each(self.f, args)
+	}
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+/// Functions that are called by value and return a value implementing [`Default`].
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{FnOnce, default::FnDefaultOnce};
+/// fn call_or_default<F: FnDefaultOnce<(i32, i32)>>(f: Option<F>, args: (i32, i32)) -> F::Output {
+///     f.map_or_else(Default::default, |f| f.call_once(args))
+/// }
+///
+/// let add = |a: i32, b: i32| a + b;
+/// assert_eq!(3, call_or_default(Some(add), (1, 2)));
+/// assert_eq!(0, call_or_default(None::<fn(i32, i32) -> i32>, (1, 2)));
+/// ```
+pub trait FnDefaultOnce<T: Tuple>: FnOnce<T, Output: Default> {}
+impl<T: Tuple, F: FnOnce<T, Output: Default>> FnDefaultOnce<T> for F {}
+
+/// Functions that can be called by mutable reference and return a value implementing [`Default`].
+pub trait FnDefaultMut<T: Tuple>: FnMut<T> + FnDefaultOnce<T> {}
+impl<T: Tuple, F: FnMut<T> + FnDefaultOnce<T>> FnDefaultMut<T> for F {}
+
+/// Functions that can be called by reference and return a value implementing [`Default`].
+pub trait FnDefault<T: Tuple>: Fn<T> + FnDefaultMut<T> {}
+impl<T: Tuple, F: Fn<T> + FnDefaultMut<T>> FnDefault<T> for F {}
+
+/// Functions that are called by value and return a value that can be turned into a [`Future`] whose output implements [`Default`].
+///
+/// # Examples
+///
+/// ```
+/// # use futures::executor::block_on;
+/// # use tupl::fns::{FnOnce, default::FnFutureDefaultOnce};
+/// async fn call_or_default<F: FnFutureDefaultOnce<(i32, i32)>>(f: Option<F>, args: (i32, i32)) -> F::FutOutput {
+///     match f {
+///         Some(f) => f.call_once(args).await,
+///         None => Default::default(),
+///     }
+/// }
+///
+/// let add = |a: i32, b: i32| async move { a + b };
+/// assert_eq!(3, block_on(call_or_default(Some(add), (1, 2))));
+/// assert_eq!(0, block_on(call_or_default(None::<fn(i32, i32) -> std::future::Ready<i32>>, (1, 2))));
+/// ```
+pub trait FnFutureDefaultOnce<T: Tuple>: future::FnFutureOnce<T, FutOutput: Default> {}
+impl<T: Tuple, F: future::FnFutureOnce<T, FutOutput: Default>> FnFutureDefaultOnce<T> for F {}
+
+/// Functions that can be called by mutable reference and return a value that can be turned into a [`Future`] whose output implements [`Default`].
+pub trait FnFutureDefaultMut<T: Tuple>: FnMut<T> + FnFutureDefaultOnce<T> {}
+impl<T: Tuple, F: FnMut<T> + FnFutureDefaultOnce<T>> FnFutureDefaultMut<T> for F {}
+
+/// Functions that can be called by reference and return a value that can be turned into a [`Future`] whose output implements [`Default`].
+pub trait FnFutureDefault<T: Tuple>: Fn<T> + FnFutureDefaultMut<T> {}
+impl<T: Tuple, F: Fn<T> + FnFutureDefaultMut<T>> FnFutureDefault<T> for F {}
@@ -0,0 +1,44 @@
+use super::*;
+
+/// Tuples of values that can be turned into [`Future`]s, which can be awaited concurrently. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait JoinFutures: Tuple {
+	/// The outputs of the [`Future`]s of this tuple.
+	type Output: Tuple;
+
+	/// Consumes this tuple and awaits all of its [`Future`]s concurrently, resolving to a tuple of their outputs.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use std::future::ready;
+	/// # use futures::executor::block_on;
+	/// # use tupl::fns::join::JoinFutures;
+	/// let futures = (async { 1 }, ready("a"), async { 3.0 });
+	/// assert_eq!((1, "a", 3.0), block_on(futures.join_all()));
+	/// assert_eq!((), block_on(().join_all()));
+	/// ```
+	fn join_all(self) -> impl Future<Output = Self::Output>;
+}
+
+/// Tuples of values that can be turned into fallible [`Future`]s sharing the same error type, which can be awaited concurrently. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait TryJoinFutures<E>: Tuple {
+	/// The successful outputs of the [`Future`]s of this tuple.
+	type Output: Tuple;
+
+	/// Consumes this tuple and awaits all of its [`Future`]s concurrently, resolving to a tuple of their successful outputs,
+	/// or to the first error as soon as one of them fails.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use std::future::ready;
+	/// # use futures::executor::block_on;
+	/// # use tupl::fns::join::TryJoinFutures;
+	/// let futures = (async { Ok::<_, &str>(1) }, ready(Ok("a")));
+	/// assert_eq!(Ok((1, "a")), block_on(futures.try_join_all()));
+	///
+	/// let futures = (async { Ok(1) }, ready(Err::<&str, _>("failed")), std::future::pending::<Result<f64, _>>());
+	/// assert_eq!(Err("failed"), block_on(futures.try_join_all()));
+	/// ```
+	fn try_join_all(self) -> impl Future<Output = Result<Self::Output, E>>;
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+/// A function whose output is mapped by another function, created using [`map_output`].
+///
+/// Calling it calls the first function, then calls the second one with the output of the first one.
+pub struct MapOutput<F, G> {
+	f: F,
+	g: G,
+}
+
+/// Maps the output of a function using another function taking it as its single argument.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{Fn, FnMut, FnOnce, TupleApply, map::map_output};
+/// let add = |a: i32, b: i32| a + b;
+/// let mapped = map_output(add, |sum: i32| sum.to_string());
+/// assert_eq!("3", mapped.call((1, 2)));
+/// assert_eq!("7", (3, 4).apply_ref(&mapped));
+///
+/// let mut calls = 0;
+/// let mut mapped = map_output(|a: i32| a * 2, |value: i32| {
+///     calls += 1;
+///     value + 1
+/// });
+/// assert_eq!(3, mapped.call_mut((1,)));
+/// assert_eq!(5, mapped.call_mut((2,)));
+/// drop(mapped);
+/// assert_eq!(2, calls);
+///
+/// let name = String::from("tupl");
+/// let mapped = map_output(move || name, |name: String| name.len());
+/// assert_eq!(4, mapped.call_once(()));
+/// ```
+#[inline]
+pub fn map_output<F, G>(f: F, g: G) -> MapOutput<F, G> {
+	MapOutput { f, g }
+}
+
+impl<T: Tuple, F: FnOnce<T>, G: core::ops::FnOnce(F::Output) -> U, U> FnOnce<T>
+	for MapOutput<F, G>
+{
+	type Output = U;
+
+	#[inline]
+	fn call_once(self, args: T) -> Self::Output {
+		(self.g)(self.f.call_once(args))
+	}
+}
+
+impl<T: Tuple, F: FnMut<T>, G: core::ops::FnMut(F::Output) -> U, U> FnMut<T> for MapOutput<F, G> {
+	#[inline]
+	fn call_mut(&mut self, args: T) -> Self::Output {
+		(self.g)(self.f.call_mut(args))
+	}
+}
+
+impl<T: Tuple, F: Fn<T>, G: core::ops::Fn(F::Output) -> U, U> Fn<T> for MapOutput<F, G> {
+	#[inline]
+	fn call(&self, args: T) -> Self::Output {
+		(self.g)(self.f.call(args))
+	}
+}
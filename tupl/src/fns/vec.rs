@@ -0,0 +1,63 @@
+use super::*;
+use alloc::vec::Vec;
+
+/// Functions that are called by value and return a [`Vec`].
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::fns::{FnOnce, vec::FnVecOnce};
+/// fn first<F: FnVecOnce<(usize,), Elem: Clone>>(f: F, len: usize) -> Option<F::Elem> {
+///     f.call_once((len,)).first().cloned()
+/// }
+///
+/// assert_eq!(Some(0), first(|len: usize| vec![0; len], 3));
+/// assert_eq!(None, first(|len: usize| vec![0; len], 0));
+/// ```
+pub trait FnVecOnce<T: Tuple>: FnOnce<T, Output = Vec<Self::Elem>> {
+	/// The type of the elements of the [`Vec`] returned by this function.
+	type Elem;
+}
+
+impl<T: Tuple, F: FnOnce<T, Output = Vec<Elem>>, Elem> FnVecOnce<T> for F {
+	type Elem = Elem;
+}
+
+/// Functions that can be called by mutable reference and return a [`Vec`].
+pub trait FnVecMut<T: Tuple>: FnMut<T> + FnVecOnce<T> {}
+impl<T: Tuple, F: FnMut<T> + FnVecOnce<T>> FnVecMut<T> for F {}
+
+/// Functions that can be called by reference and return a [`Vec`].
+pub trait FnVec<T: Tuple>: Fn<T> + FnVecMut<T> {}
+impl<T: Tuple, F: Fn<T> + FnVecMut<T>> FnVec<T> for F {}
+
+/// Functions that are called by value and return a value that can be turned into a [`Future`] whose output is a [`Vec`].
+///
+/// # Examples
+///
+/// ```
+/// # use futures::executor::block_on;
+/// # use tupl::fns::{FnOnce, vec::FnFutureVecOnce};
+/// async fn first<F: FnFutureVecOnce<(usize,), Elem: Clone>>(f: F, len: usize) -> Option<F::Elem> {
+///     f.call_once((len,)).await.first().cloned()
+/// }
+///
+/// assert_eq!(Some(0), block_on(first(|len: usize| async move { vec![0; len] }, 3)));
+/// assert_eq!(None, block_on(first(|len: usize| async move { vec![0; len] }, 0)));
+/// ```
+pub trait FnFutureVecOnce<T: Tuple>: future::FnFutureOnce<T, FutOutput = Vec<Self::Elem>> {
+	/// The type of the elements of the [`Vec`] output by the [`Future`] returned by this function.
+	type Elem;
+}
+
+impl<T: Tuple, F: future::FnFutureOnce<T, FutOutput = Vec<Elem>>, Elem> FnFutureVecOnce<T> for F {
+	type Elem = Elem;
+}
+
+/// Functions that can be called by mutable reference and return a value that can be turned into a [`Future`] whose output is a [`Vec`].
+pub trait FnFutureVecMut<T: Tuple>: FnMut<T> + FnFutureVecOnce<T> {}
+impl<T: Tuple, F: FnMut<T> + FnFutureVecOnce<T>> FnFutureVecMut<T> for F {}
+
+/// Functions that can be called by reference and return a value that can be turned into a [`Future`] whose output is a [`Vec`].
+pub trait FnFutureVec<T: Tuple>: Fn<T> + FnFutureVecMut<T> {}
+impl<T: Tuple, F: Fn<T> + FnFutureVecMut<T>> FnFutureVec<T> for F {}
@@ -0,0 +1,30 @@
+use super::*;
+
+/// Extension methods for iterators.
+pub trait IteratorExt: Iterator + Sized {
+	/// Creates a tuple from the first items of this iterator, or returns `None` if it yields fewer items than the arity of the tuple.
+	///
+	/// This is the iterator-side companion of [`HomogeneousTuple::try_from_iter`], see it for what happens to the extra items.
+	///
+	/// Unlike `Itertools::collect_tuple` from the `itertools` crate, which returns `None` when the iterator yields extra items,
+	/// this method ignores them. Both methods have the same name, so calling it with both traits in scope is ambiguous,
+	/// in which case it has to be called as `IteratorExt::collect_tuple(iter)`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::iter::IteratorExt;
+	/// assert_eq!(Some((1, 2, 3)), [1, 2, 3].into_iter().collect_tuple());
+	/// assert_eq!(None, [1, 2].into_iter().collect_tuple::<(i32, i32, i32)>());
+	///
+	/// let mut iter = 1..;
+	/// assert_eq!(Some((1, 2)), iter.by_ref().collect_tuple());
+	/// assert_eq!(Some(3), iter.next());
+	/// ```
+	#[inline]
+	fn collect_tuple<T: HomogeneousTuple<Self::Item>>(self) -> Option<T> {
+		T::try_from_iter(self)
+	}
+}
+
+impl<I: Iterator> IteratorExt for I {}
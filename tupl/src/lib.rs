@@ -3,8 +3,16 @@
 #![forbid(unsafe_code)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Function related traits.
 pub mod fns;
+
+/// Iterator related traits.
+pub mod iter;
+
+use core::any::Any;
 use fns::*;
 
 // Sealed trait.
@@ -15,7 +23,7 @@ mod seal {
 /// Get the type at a given index of tuple `T`.
 pub type TupleIndex<T, const INDEX: usize> = <T as IndexableTuple<INDEX>>::Value;
 
-/// Tuples of unknown size. Implemented for tuples of arity 0 to 32.
+/// Tuples of unknown size. Implemented for tuples of arity 0 to the [maximum arity](crate#maximum-arity), and references to them.
 pub trait DynTuple: seal::Sealed {
 	/// The [arity](https://en.wikipedia.org/wiki/Arity) (or length) of this tuple.
 	/// 
@@ -27,15 +35,141 @@ pub trait DynTuple: seal::Sealed {
 	/// assert_eq!(3, tuple.arity());
 	/// ```
 	fn arity(&self) -> usize;
+
+	/// Returns the range of the valid indices of this tuple, from `0` to its arity.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{AnyTuple, DynTuple};
+	/// let tuple = (1u8, "x", 3.0f64);
+	/// assert_eq!(0..3, tuple.positions());
+	/// for index in tuple.positions() {
+	///     assert!(tuple.get_any(index).is_some());
+	/// }
+	/// assert!(().positions().is_empty());
+	/// ```
+	#[inline]
+	fn positions(&self) -> core::ops::Range<usize> {
+		0..self.arity()
+	}
+
+	/// Returns an iterator over the labels of the fields of this tuple, in order, as they would be written to access them.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::DynTuple;
+	/// let tuple = (1, "a", 3.0, 'd');
+	/// let labels: Vec<&str> = tuple.field_labels().collect();
+	/// assert_eq!(vec![".0", ".1", ".2", ".3"], labels);
+	/// assert_eq!(0, ().field_labels().len());
+	/// ```
+	#[inline]
+	fn field_labels(&self) -> core::iter::Copied<core::slice::Iter<'static, &'static str>> {
+		FIELD_LABELS[..self.arity()].iter().copied()
+	}
+}
+
+impl<T: DynTuple + ?Sized> seal::Sealed for &T {}
+impl<T: DynTuple + ?Sized> seal::Sealed for &mut T {}
+
+/// Borrowed tuples have the same arity as the tuple they borrow.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::DynTuple;
+/// fn arity_of(tuple: impl DynTuple) -> usize {
+///     tuple.arity()
+/// }
+///
+/// let mut tuple = (1, 2);
+/// assert_eq!(2, (&tuple).arity());
+/// assert_eq!(2, arity_of(&tuple));
+/// assert_eq!(2, arity_of(&mut tuple));
+/// ```
+impl<T: DynTuple + ?Sized> DynTuple for &T {
+	#[inline]
+	fn arity(&self) -> usize {
+		T::arity(self)
+	}
+}
+
+impl<T: DynTuple + ?Sized> DynTuple for &mut T {
+	#[inline]
+	fn arity(&self) -> usize {
+		T::arity(self)
+	}
+}
+
+/// Tuples whose elements are all `'static`, allowing them to be accessed dynamically. Implemented for tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait AnyTuple: DynTuple {
+	/// Returns a type-erased reference to the element at the given index, or `None` if it is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::AnyTuple;
+	/// let tuple = (1u8, "x", 3.0f64);
+	/// let value = tuple.get_any(2).and_then(|value| value.downcast_ref::<f64>());
+	/// assert_eq!(Some(&3.0), value);
+	/// assert!(tuple.get_any(3).is_none());
+	/// ```
+	fn get_any(&self, index: usize) -> Option<&dyn Any>;
+
+	/// Returns a type-erased mutable reference to the element at the given index, or `None` if it is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::AnyTuple;
+	/// let mut tuple = (1i32, 2i32);
+	/// if let Some(value) = tuple.get_any_mut(1).and_then(|value| value.downcast_mut::<i32>()) {
+	///     *value += 10;
+	/// }
+	/// assert_eq!((1, 12), tuple);
+	/// assert!(tuple.get_any_mut(2).is_none());
+	/// ```
+	fn get_any_mut(&mut self, index: usize) -> Option<&mut dyn Any>;
+
+	/// Returns whether all the elements of this tuple share the same type. The empty and unary tuples are always homogeneous.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::AnyTuple;
+	/// assert!((1i32, 2i32).is_homogeneous());
+	/// assert!(!(1i32, 2u8).is_homogeneous());
+	/// assert!((1i32,).is_homogeneous());
+	/// assert!(().is_homogeneous());
+	/// ```
+	fn is_homogeneous(&self) -> bool;
+
+	/// Returns an iterator over the [`TypeId`](core::any::TypeId) of each element of this tuple, in order, without accessing their values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::AnyTuple;
+	/// # use core::any::TypeId;
+	/// let tuple = (1u8, "x", 3.0f64);
+	/// let type_ids: Vec<TypeId> = tuple.type_ids().collect();
+	/// assert_eq!(vec![TypeId::of::<u8>(), TypeId::of::<&str>(), TypeId::of::<f64>()], type_ids);
+	/// assert_eq!(0, ().type_ids().len());
+	/// ```
+	fn type_ids(&self) -> impl DoubleEndedIterator<Item = core::any::TypeId> + ExactSizeIterator
+	where
+		Self: Sized;
 }
 
-/// Tuples with a known size. Implemented for sized tuples of arity 0 to 32.
+/// Tuples with a known size. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
 pub trait Tuple: DynTuple + Sized {
 	/// The [arity](https://en.wikipedia.org/wiki/Arity) (or length) of this tuple.
 	const ARITY: usize;
 }
 
-/// Tuples that can be joined together. Implemented for tuples of arity 0 to 32.
+/// Tuples that can be joined together. Implemented for tuples of arity 0 to the [maximum arity](crate#maximum-arity).
 pub trait JoinableTuple<T: JoinableTuple<Self>>: Tuple {
 	/// This tuple joined with another tuple.
 	type Join: Tuple;
@@ -54,7 +188,50 @@ pub trait JoinableTuple<T: JoinableTuple<Self>>: Tuple {
 	fn join(self, other: T) -> Self::Join;
 }
 
-/// Tuples that can grow. Implemented for sized tuples of arity 0 to 31.
+/// Tuples that can be extended with the elements of another tuple. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait ExtendableTuple: Tuple {
+	/// Consumes this tuple and appends the elements of another tuple to it. This is an alias of [`join`](JoinableTuple::join).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{ExtendableTuple, GrowableTuple};
+	/// let tuple = (1, 2);
+	/// assert_eq!((1, 2, (3, 4)), tuple.append((3, 4)));
+	/// assert_eq!((1, 2, 3, 4), tuple.append_tuple((3, 4)));
+	/// ```
+	#[inline]
+	fn append_tuple<T>(self, other: T) -> <Self as JoinableTuple<T>>::Join
+	where
+		Self: JoinableTuple<T>,
+		T: JoinableTuple<Self>,
+	{
+		self.join(other)
+	}
+
+	/// Consumes this tuple and prepends the elements of another tuple to it.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{ExtendableTuple, GrowableTuple};
+	/// let tuple = (3, 4);
+	/// assert_eq!(((1, 2), 3, 4), tuple.prepend((1, 2)));
+	/// assert_eq!((1, 2, 3, 4), tuple.prepend_tuple((1, 2)));
+	/// ```
+	#[inline]
+	fn prepend_tuple<T>(self, other: T) -> <T as JoinableTuple<Self>>::Join
+	where
+		Self: JoinableTuple<T>,
+		T: JoinableTuple<Self>,
+	{
+		other.join(self)
+	}
+}
+
+impl<T: Tuple> ExtendableTuple for T {}
+
+/// Tuples that can grow. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity) minus one.
 pub trait GrowableTuple: Tuple {
 	/// This tuple with an extra element `T` appended to it.
 	type Append<T>: NonEmptyTuple<TruncateTail = Self, Tail = T>;
@@ -87,7 +264,7 @@ pub trait GrowableTuple: Tuple {
 	fn prepend<T>(self, value: T) -> Self::Prepend<T>;
 }
 
-/// Tuples that are not empty. Implemented for sized tuples of arity 1 to 32.
+/// Tuples that are not empty. Implemented for sized tuples of arity 1 to the [maximum arity](crate#maximum-arity).
 pub trait NonEmptyTuple: Tuple {
 	/// The first element of this tuple.
 	type Head;
@@ -168,9 +345,52 @@ pub trait NonEmptyTuple: Tuple {
 	/// assert_eq!(((1, 2), 3), (tuple, tail));
 	/// ```
 	fn truncate_tail(self) -> (Self::TruncateTail, Self::Tail);
+
+	/// Returns a reference to the head of this tuple along with a tuple of references to the remaining elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NonEmptyTuple;
+	/// let tuple = (1, String::from("a"), 3.0);
+	/// let (head, rest) = tuple.split_first_ref();
+	/// assert_eq!((&1, (&String::from("a"), &3.0)), (head, rest));
+	/// assert_eq!((&1, ()), (1,).split_first_ref());
+	/// ```
+	fn split_first_ref(&self) -> (&Self::Head, <Self::TruncateHead as RefTuple>::Refs<'_>)
+	where
+		Self::TruncateHead: RefTuple;
+
+	/// Consumes this tuple and returns its head, dropping the remaining elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NonEmptyTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert_eq!(1, tuple.into_head());
+	/// ```
+	#[inline]
+	fn into_head(self) -> Self::Head {
+		self.truncate_head().0
+	}
+
+	/// Consumes this tuple and returns its tail, dropping the remaining elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NonEmptyTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert_eq!(3, tuple.into_tail());
+	/// ```
+	#[inline]
+	fn into_tail(self) -> Self::Tail {
+		self.truncate_tail().1
+	}
 }
 
-/// Tuples that not unary nor empty. Implemented for sized tuples of arity 2 to 32.
+/// Tuples that not unary nor empty. Implemented for sized tuples of arity 2 to the [maximum arity](crate#maximum-arity).
 pub trait NonUnaryTuple: NonEmptyTuple<TruncateHead: NonEmptyTuple<Tail = Self::Tail>, TruncateTail: NonEmptyTuple<Head = Self::Head>> {
 	/// This tuple with its head and tail truncated.
 	type TruncateHeadTail: GrowableTuple<Prepend<Self::Head> = Self::TruncateTail, Append<Self::Tail> = Self::TruncateHead>;
@@ -210,9 +430,69 @@ pub trait NonUnaryTuple: NonEmptyTuple<TruncateHead: NonEmptyTuple<Tail = Self::
 	/// assert_eq!((1, (2, 3), 4), (head, tuple, tail));
 	/// ```
 	fn truncate_head_tail(self) -> (Self::Head, Self::TruncateHeadTail, Self::Tail);
+
+	/// Consumes this tuple and returns its head and tail, dropping the remaining elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NonUnaryTuple;
+	/// let tuple = (1, 2, 3, 4);
+	/// assert_eq!((1, 4), tuple.into_head_tail());
+	/// ```
+	#[inline]
+	fn into_head_tail(self) -> (Self::Head, Self::Tail) {
+		let (head, _, tail) = self.truncate_head_tail();
+		(head, tail)
+	}
+
+	/// Consumes this tuple and maps its head to a new value, keeping the other elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NonUnaryTuple;
+	/// let tuple = (1, "a", 3.0);
+	/// let tuple: (String, &str, f64) = tuple.map_head(|head| head.to_string());
+	/// assert_eq!((String::from("1"), "a", 3.0), tuple);
+	/// ```
+	///
+	/// Unary tuples, whose head is also their tail, can't have only their head mapped.
+	///
+	/// ```compile_fail
+	/// # use tupl::NonUnaryTuple;
+	/// let tuple = (1,).map_head(|head| head > 0);
+	/// ```
+	#[inline]
+	fn map_head<U, F: core::ops::FnOnce(Self::Head) -> U>(
+		self,
+		f: F,
+	) -> <Self::TruncateHead as GrowableTuple>::Prepend<U> {
+		let (head, rest) = self.truncate_head();
+		rest.prepend(f(head))
+	}
+
+	/// Consumes this tuple and maps its tail to a new value, keeping the other elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NonUnaryTuple;
+	/// let tuple = (1, "a", 3.0);
+	/// let tuple: (i32, &str, bool) = tuple.map_tail(|tail| tail > 2.0);
+	/// assert_eq!((1, "a", true), tuple);
+	/// ```
+	#[inline]
+	fn map_tail<U, F: core::ops::FnOnce(Self::Tail) -> U>(
+		self,
+		f: F,
+	) -> <Self::TruncateTail as GrowableTuple>::Append<U> {
+		let (rest, tail) = self.truncate_tail();
+		rest.append(f(tail))
+	}
 }
 
-/// Tuples that can be indexed. Implemented for sized tuples of arity 1 to 32.
+/// Tuples that can be indexed. Implemented for sized tuples of arity 1 to the [maximum arity](crate#maximum-arity).
 pub trait IndexableTuple<const INDEX: usize>: NonEmptyTuple {
 	/// The type of the value at the given index.
 	type Value;
@@ -239,6 +519,25 @@ pub trait IndexableTuple<const INDEX: usize>: NonEmptyTuple {
 	/// ```
 	fn get_mut(&mut self) -> &mut Self::Value;
 
+	/// Returns a copy of the value at the given index, without consuming this tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::IndexableTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert_eq!(2, IndexableTuple::<1>::get_copy(&tuple));
+	/// assert_eq!(2, IndexableTuple::<1>::get_copy(&tuple));
+	/// assert_eq!((1, 2, 3), tuple);
+	/// ```
+	#[inline]
+	fn get_copy(&self) -> Self::Value
+	where
+		Self::Value: Copy,
+	{
+		*self.get()
+	}
+
 	/// Consumes this tuple and returns the value at the given index.
 	/// 
 	/// # Examples
@@ -249,7 +548,1814 @@ pub trait IndexableTuple<const INDEX: usize>: NonEmptyTuple {
 	/// assert_eq!(2, IndexableTuple::<1>::into_index(tuple));
 	/// ```
 	fn into_index(self) -> Self::Value;
+
+	/// Consumes this tuple and returns it with the value at the given index overwritten.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// # use tupl::IndexableTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert_eq!((1, 9, 3), IndexableTuple::<1>::set(tuple, 9));
+	/// ```
+	fn set(self, value: Self::Value) -> Self;
+}
+
+/// Tuples that can be borrowed as a tuple of (mutable) references. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait RefTuple: Tuple {
+	/// This tuple with each of its elements borrowed.
+	type Refs<'a>: Tuple
+	where
+		Self: 'a;
+
+	/// This tuple with each of its elements mutably borrowed.
+	type RefsMut<'a>: Tuple
+	where
+		Self: 'a;
+
+	/// Returns a tuple containing a reference to each element of this tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::RefTuple;
+	/// let tuple = (1, "a", 3.0);
+	/// let (a, b, c) = tuple.as_refs();
+	/// assert_eq!((&1, &"a", &3.0), (a, b, c));
+	/// ```
+	fn as_refs<'a>(&'a self) -> Self::Refs<'a>;
+
+	/// Returns a tuple containing a mutable reference to each element of this tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::RefTuple;
+	/// let mut tuple = (1, 2, 3);
+	/// let (a, b, c) = tuple.as_muts();
+	/// *a += 10;
+	/// *b += 20;
+	/// *c += 30;
+	/// assert_eq!((11, 22, 33), tuple);
+	/// ```
+	fn as_muts<'a>(&'a mut self) -> Self::RefsMut<'a>;
 }
 
+/// Tuples whose elements all share the same type `T`. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait HomogeneousTuple<T>: Tuple {
+	/// An array of `T` with the same length as this tuple.
+	type Array: IntoIterator<Item = T>;
+
+	/// This tuple with each of its elements replaced by an element `U`.
+	type Mapped<U>: HomogeneousTuple<U>;
+
+	/// Consumes this tuple and turns it into an array.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1u8, 2u8, 3u8);
+	/// assert_eq!([1, 2, 3], tuple.into_array());
+	/// ```
+	///
+	/// Tuples can't implement [`IntoIterator`] themselves, as both the trait and the tuple types are foreign to this crate,
+	/// but iterating over the array yields the elements by value.
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3);
+	/// let mut sum = 0;
+	/// for value in tuple.into_array() {
+	///     sum += value;
+	/// }
+	/// assert_eq!(6, sum);
+	/// assert_eq!(vec![1, 2, 3], tuple.into_array().into_iter().collect::<Vec<_>>());
+	/// ```
+	///
+	/// Tuples whose elements don't share the same type can't be turned into arrays.
+	///
+	/// ```compile_fail
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1u8, 2u16, 3u8);
+	/// let array = tuple.into_array();
+	/// ```
+	fn into_array(self) -> Self::Array;
+
+	/// Creates a tuple from an array of the same length.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple: (i32, i32, i32) = HomogeneousTuple::from_array([1, 2, 3]);
+	/// assert_eq!((1, 2, 3), tuple);
+	/// assert_eq!([1, 2, 3], tuple.into_array());
+	/// ```
+	fn from_array(array: Self::Array) -> Self;
+
+	/// Creates a tuple from the first items of an iterator, or returns `None` if it yields fewer items than the arity of the tuple.
+	///
+	/// Extra items are dropped; pass `&mut iter` (or [`by_ref`](Iterator::by_ref)) to keep the remainder.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple: Option<(i32, i32, i32)> = HomogeneousTuple::try_from_iter([1, 2, 3]);
+	/// assert_eq!(Some((1, 2, 3)), tuple);
+	///
+	/// let tuple: Option<(i32, i32, i32)> = HomogeneousTuple::try_from_iter(1..);
+	/// assert_eq!(Some((1, 2, 3)), tuple);
+	///
+	/// let tuple: Option<(i32, i32, i32)> = HomogeneousTuple::try_from_iter([1, 2]);
+	/// assert_eq!(None, tuple);
+	///
+	/// let mut iter = 1..;
+	/// let tuple: Option<(i32, i32)> = HomogeneousTuple::try_from_iter(&mut iter);
+	/// assert_eq!(Some((1, 2)), tuple);
+	/// assert_eq!(Some(3), iter.next());
+	/// ```
+	fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Option<Self>;
+
+	/// Creates a tuple by cloning `value` into each of its elements, in order.
+	///
+	/// The last element takes `value` itself, so it is cloned one time less than the arity.
+	/// For the empty tuple, `value` is simply dropped.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple: (u8, u8, u8) = HomogeneousTuple::repeat(0);
+	/// assert_eq!((0, 0, 0), tuple);
+	///
+	/// let empty: () = HomogeneousTuple::repeat(String::from("dropped"));
+	/// assert_eq!((), empty);
+	/// ```
+	fn repeat(value: T) -> Self
+	where
+		T: Clone;
+
+	/// Returns a reference to the element at the given index, or `None` if it is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (10, 20, 30);
+	/// assert_eq!(Some(&20), tuple.get_dyn(1));
+	/// assert_eq!(None, tuple.get_dyn(3));
+	/// ```
+	fn get_dyn(&self, index: usize) -> Option<&T>;
+
+	/// Returns a mutable reference to the element at the given index, or `None` if it is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let mut tuple = (10, 20, 30);
+	/// if let Some(value) = tuple.get_dyn_mut(2) {
+	///     *value += 5;
+	/// }
+	/// assert_eq!((10, 20, 35), tuple);
+	/// assert_eq!(None, tuple.get_dyn_mut(3));
+	/// ```
+	fn get_dyn_mut(&mut self, index: usize) -> Option<&mut T>;
+
+	/// Returns a reference to the element at the given index.
+	///
+	/// Tuples can't implement [`Index`](core::ops::Index) themselves, for the same reason as explained in [`into_array`](HomogeneousTuple::into_array).
+	///
+	/// # Panics
+	///
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (10, 20, 30);
+	/// assert_eq!(&20, tuple.index(1));
+	/// ```
+	///
+	/// ```should_panic
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (10, 20, 30);
+	/// let value = tuple.index(3);
+	/// ```
+	#[inline]
+	fn index(&self, index: usize) -> &T {
+		match self.get_dyn(index) {
+			Some(value) => value,
+			None => panic!(
+				"index out of bounds: the arity is {} but the index is {}",
+				Self::ARITY,
+				index
+			),
+		}
+	}
+
+	/// Returns a mutable reference to the element at the given index.
+	///
+	/// # Panics
+	///
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let mut tuple = (10, 20, 30);
+	/// *tuple.index_mut(1) += 5;
+	/// assert_eq!((10, 25, 30), tuple);
+	/// ```
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		match self.get_dyn_mut(index) {
+			Some(value) => value,
+			None => panic!(
+				"index out of bounds: the arity is {} but the index is {}",
+				Self::ARITY,
+				index
+			),
+		}
+	}
+
+	/// Returns an iterator over references to the elements of this tuple.
+	///
+	/// Tuples can't be borrowed as slices or arrays, as the layout of their elements in memory isn't guaranteed,
+	/// so this iterator is the way to go through them without moving the tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3);
+	/// let mut iter = tuple.iter();
+	/// assert_eq!(3, iter.len());
+	/// assert_eq!(Some(&1), iter.next());
+	/// assert_eq!(Some(&2), iter.next());
+	/// assert_eq!(Some(&3), iter.next());
+	/// assert_eq!(None, iter.next());
+	/// ```
+	fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator
+	where
+		T: 'a;
+
+	/// Returns an iterator over mutable references to the elements of this tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let mut tuple = (1.0, 2.0, 3.0);
+	/// tuple.iter_mut().for_each(|value| *value *= 2.0);
+	/// assert_eq!((2.0, 4.0, 6.0), tuple);
+	/// assert_eq!(12.0, tuple.iter().sum::<f64>());
+	/// ```
+	fn iter_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut T> + ExactSizeIterator
+	where
+		T: 'a;
+
+	/// Returns an iterator over clones of the elements of this tuple, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (String::from("a"), String::from("b"));
+	/// let mut clones: Vec<String> = tuple.cloned().collect();
+	/// clones[0].push('!');
+	/// assert_eq!(vec![String::from("a!"), String::from("b")], clones);
+	/// assert_eq!((String::from("a"), String::from("b")), tuple);
+	/// ```
+	#[inline]
+	fn cloned<'a>(&'a self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator
+	where
+		T: 'a + Clone,
+	{
+		self.iter().cloned()
+	}
+
+	/// Returns whether all the elements of this tuple satisfy a predicate, stopping at the first one that doesn't.
+	///
+	/// The empty tuple always returns `true`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// assert!((1, 2, 3).all(|value| *value > 0));
+	/// assert!(!(1, 2, 3).all(|value| *value > 1));
+	/// assert!(HomogeneousTuple::<i32>::all(&(), |value| *value > 0));
+	/// ```
+	#[inline]
+	fn all<F: core::ops::FnMut(&T) -> bool>(&self, f: F) -> bool {
+		self.iter().all(f)
+	}
+
+	/// Returns whether any element of this tuple satisfies a predicate, stopping at the first one that does.
+	///
+	/// The empty tuple always returns `false`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let mut visited = Vec::new();
+	/// assert!((1, 2, 3).any(|value| {
+	///     visited.push(*value);
+	///     *value > 1
+	/// }));
+	/// assert_eq!(vec![1, 2], visited);
+	/// assert!(!(1, 2, 3).any(|value| *value > 3));
+	/// assert!(!HomogeneousTuple::<i32>::any(&(), |value| *value > 0));
+	/// ```
+	#[inline]
+	fn any<F: core::ops::FnMut(&T) -> bool>(&self, f: F) -> bool {
+		self.iter().any(f)
+	}
+
+	/// Returns the number of elements of this tuple satisfying a predicate, checking them in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (Some(1), None, Some(3), None);
+	/// assert_eq!(2, tuple.count(|value| value.is_some()));
+	/// assert_eq!(0, HomogeneousTuple::<i32>::count(&(), |_| true));
+	/// ```
+	#[inline]
+	fn count<F: core::ops::FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+		self.iter().filter(|value| f(value)).count()
+	}
+
+	/// Returns whether this tuple contains an element equal to the given value, comparing its elements in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert!(tuple.contains(&2));
+	/// assert!(!tuple.contains(&4));
+	/// assert!(!HomogeneousTuple::<i32>::contains(&(), &1));
+	/// ```
+	fn contains(&self, value: &T) -> bool
+	where
+		T: PartialEq;
+
+	/// Returns the index of the first element of this tuple equal to the given value, or `None` if there is none.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = ("a", "b", "a");
+	/// assert_eq!(Some(0), tuple.position(&"a"));
+	/// assert_eq!(Some(1), tuple.position(&"b"));
+	/// assert_eq!(None, tuple.position(&"c"));
+	/// assert_eq!(None, HomogeneousTuple::<&str>::position(&(), &"a"));
+	/// ```
+	fn position(&self, value: &T) -> Option<usize>
+	where
+		T: PartialEq;
+
+	/// Consumes this tuple and folds its elements into an accumulator, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert_eq!(6, tuple.fold(0, |acc, value| acc + value));
+	/// assert_eq!(0, ().fold(0, |acc, value: i32| acc + value));
+	/// ```
+	fn fold<B, F: core::ops::FnMut(B, T) -> B>(self, init: B, f: F) -> B;
+
+	/// Consumes this tuple and folds its elements into an accumulator, in order, stopping at the first error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let checked_sum = |acc: u8, value: u8| acc.checked_add(value).ok_or(value);
+	/// assert_eq!(Ok(6), (1, 2, 3).try_fold(0, checked_sum));
+	/// assert_eq!(Err(200), (100, 200, 3).try_fold(0, checked_sum));
+	///
+	/// let mut visited = Vec::new();
+	/// let result = (1, -2, 3).try_fold(0, |acc, value| {
+	///     visited.push(value);
+	///     if value < 0 { Err(value) } else { Ok(acc + value) }
+	/// });
+	/// assert_eq!(Err(-2), result);
+	/// assert_eq!(vec![1, -2], visited);
+	/// ```
+	fn try_fold<B, E, F: core::ops::FnMut(B, T) -> Result<B, E>>(
+		self,
+		init: B,
+		f: F,
+	) -> Result<B, E>;
+
+	/// Consumes this tuple and maps each of its elements to a new value, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3);
+	/// let strings: (String, String, String) = tuple.map(|value| value.to_string());
+	/// assert_eq!(("1", "2", "3"), (&*strings.0, &*strings.1, &*strings.2));
+	/// assert_eq!((), ().map(|value: i32| value.to_string()));
+	///
+	/// let mut order = Vec::new();
+	/// (1, 2, 3).map(|value| order.push(value));
+	/// assert_eq!(vec![1, 2, 3], order);
+	/// ```
+	fn map<U, F: core::ops::FnMut(T) -> U>(self, f: F) -> Self::Mapped<U>;
+
+	/// Consumes this tuple and maps each of its elements to a new value, in order, stopping at the first error.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let parsed: Result<(i32, i32), _> = ("1", "2").try_map(str::parse::<i32>);
+	/// assert_eq!(Ok((1, 2)), parsed);
+	///
+	/// let mut visited = Vec::new();
+	/// let parsed = ("1", "x", "3").try_map(|value| {
+	///     visited.push(value);
+	///     value.parse::<i32>()
+	/// });
+	/// assert!(parsed.is_err());
+	/// assert_eq!(vec!["1", "x"], visited);
+	/// ```
+	fn try_map<U, E, F: core::ops::FnMut(T) -> Result<U, E>>(
+		self,
+		f: F,
+	) -> Result<Self::Mapped<U>, E>;
+
+	/// Consumes this tuple and maps each of its elements to a new value along with its index, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = ("a", "b", "c");
+	/// let labeled = tuple.map_indexed(|index, value| (index, value));
+	/// assert_eq!(((0, "a"), (1, "b"), (2, "c")), labeled);
+	/// ```
+	fn map_indexed<U, F: core::ops::FnMut(usize, T) -> U>(self, f: F) -> Self::Mapped<U>;
+
+	/// Consumes this tuple and another tuple of the same arity and combines their elements pairwise, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let sums = (1, 2, 3).zip_with((10, 20, 30), |a, b| a + b);
+	/// assert_eq!((11, 22, 33), sums);
+	///
+	/// let names = ("Alice", "Bob").zip_with(("Smith", "Jones"), |first, last| format!("{first} {last}"));
+	/// assert_eq!((String::from("Alice Smith"), String::from("Bob Jones")), names);
+	/// ```
+	fn zip_with<O, U, F: core::ops::FnMut(T, O) -> U>(
+		self,
+		other: Self::Mapped<O>,
+		f: F,
+	) -> Self::Mapped<U>;
+
+	/// Consumes this tuple and maps each of its elements to a new value using a mutable state, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let sums = (1, 2, 3).scan(0, |sum, value| {
+	///     *sum += value;
+	///     *sum
+	/// });
+	/// assert_eq!((1, 3, 6), sums);
+	///
+	/// let lengths = ("a", "b", "c").scan(String::new(), |prefix, value| {
+	///     prefix.push_str(value);
+	///     prefix.len()
+	/// });
+	/// assert_eq!((1, 2, 3), lengths);
+	/// ```
+	fn scan<St, U, F: core::ops::FnMut(&mut St, T) -> U>(self, init: St, f: F) -> Self::Mapped<U>;
+
+	/// Consumes this tuple and combines its elements using an accumulator function, using its head as the initial value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// assert_eq!(10, (1, 2, 3, 4).reduce(|acc, value| acc + value));
+	/// assert_eq!(1, (1,).reduce(|acc, value| acc + value));
+	/// ```
+	///
+	/// The empty tuple has no head to start from.
+	///
+	/// ```compile_fail
+	/// # use tupl::HomogeneousTuple;
+	/// let reduced: i32 = HomogeneousTuple::<i32>::reduce((), |acc: i32, value: i32| acc + value);
+	/// ```
+	#[inline]
+	fn reduce<F: core::ops::FnMut(T, T) -> T>(self, f: F) -> T
+	where
+		Self: NonEmptyTuple<Head = T, TruncateHead: HomogeneousTuple<T>>,
+	{
+		let (head, rest) = self.truncate_head();
+		rest.fold(head, f)
+	}
+
+	/// Consumes this tuple and returns its minimum element, or the first one if several are equally minimum.
+	///
+	/// Unlike [`Ord::min`], which tuples also implement when their elements do, this compares the elements of a single tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// assert_eq!(1, (3, 1, 2).min_elem());
+	/// assert_eq!(7, (7,).min_elem());
+	/// ```
+	#[inline]
+	fn min_elem(self) -> T
+	where
+		T: Ord,
+		Self: NonEmptyTuple<Head = T, TruncateHead: HomogeneousTuple<T>>,
+	{
+		self.min_by(T::cmp)
+	}
+
+	/// Consumes this tuple and returns its maximum element, or the last one if several are equally maximum.
+	///
+	/// Unlike [`Ord::max`], which tuples also implement when their elements do, this compares the elements of a single tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// assert_eq!(3, (3, 1, 2).max_elem());
+	/// assert_eq!(7, (7,).max_elem());
+	/// ```
+	///
+	/// The empty tuple has no maximum element.
+	///
+	/// ```compile_fail
+	/// # use tupl::HomogeneousTuple;
+	/// let max: i32 = HomogeneousTuple::<i32>::max_elem(());
+	/// ```
+	#[inline]
+	fn max_elem(self) -> T
+	where
+		T: Ord,
+		Self: NonEmptyTuple<Head = T, TruncateHead: HomogeneousTuple<T>>,
+	{
+		self.max_by(T::cmp)
+	}
+
+	/// Consumes this tuple and returns its minimum element according to a comparison function, or the first one if several are equally minimum.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (2.5f64, -1.0, 3.0);
+	/// assert_eq!(-1.0, tuple.min_by(|a, b| a.total_cmp(b)));
+	///
+	/// let words = ("bb", "a", "c");
+	/// assert_eq!("a", words.min_by(|a, b| a.len().cmp(&b.len())));
+	/// ```
+	#[inline]
+	fn min_by<F: core::ops::FnMut(&T, &T) -> core::cmp::Ordering>(self, mut compare: F) -> T
+	where
+		Self: NonEmptyTuple<Head = T, TruncateHead: HomogeneousTuple<T>>,
+	{
+		self.reduce(|min, value| match compare(&min, &value) {
+			core::cmp::Ordering::Greater => value,
+			_ => min,
+		})
+	}
+
+	/// Consumes this tuple and returns its maximum element according to a comparison function, or the last one if several are equally maximum.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (2.5f64, -1.0, 3.0);
+	/// assert_eq!(3.0, tuple.max_by(|a, b| a.total_cmp(b)));
+	///
+	/// let words = ("a", "bb", "cc");
+	/// assert_eq!("cc", words.max_by(|a, b| a.len().cmp(&b.len())));
+	/// ```
+	#[inline]
+	fn max_by<F: core::ops::FnMut(&T, &T) -> core::cmp::Ordering>(self, mut compare: F) -> T
+	where
+		Self: NonEmptyTuple<Head = T, TruncateHead: HomogeneousTuple<T>>,
+	{
+		self.reduce(|max, value| match compare(&max, &value) {
+			core::cmp::Ordering::Greater => max,
+			_ => value,
+		})
+	}
+
+	/// Consumes this tuple and sums its elements, in order.
+	///
+	/// The sum of the empty tuple is the sum of an empty iterator, such as `0` for numbers.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// assert_eq!(6, (1, 2, 3).sum());
+	/// assert_eq!(4.5, (1.5, 3.0).sum());
+	/// assert_eq!(0, HomogeneousTuple::<i32>::sum(()));
+	/// ```
+	#[inline]
+	fn sum(self) -> T
+	where
+		T: core::iter::Sum,
+	{
+		self.into_array().into_iter().sum()
+	}
+
+	/// Consumes this tuple and multiplies its elements, in order.
+	///
+	/// The product of the empty tuple is the product of an empty iterator, such as `1` for numbers.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// assert_eq!(24, (2, 3, 4).product());
+	/// assert_eq!(4.5, (1.5, 3.0).product());
+	/// assert_eq!(1, HomogeneousTuple::<i32>::product(()));
+	/// ```
+	#[inline]
+	fn product(self) -> T
+	where
+		T: core::iter::Product,
+	{
+		self.into_array().into_iter().product()
+	}
+
+	/// Consumes this tuple and calls a function on each of its elements, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let mut values = Vec::new();
+	/// (1, 2, 3).for_each(|value| values.push(value));
+	/// assert_eq!(vec![1, 2, 3], values);
+	/// ```
+	#[inline]
+	fn for_each<F: core::ops::FnMut(T)>(self, mut f: F) {
+		self.fold((), |(), value| f(value));
+	}
+
+	/// Calls a function on a reference to each of the elements of this tuple, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3);
+	/// let mut values = Vec::new();
+	/// tuple.for_each_ref(|value| values.push(*value));
+	/// assert_eq!(vec![1, 2, 3], values);
+	/// ```
+	#[inline]
+	fn for_each_ref<F: core::ops::FnMut(&T)>(&self, f: F) {
+		self.iter().for_each(f);
+	}
+
+	/// Calls a function on a mutable reference to each of the elements of this tuple, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let mut tuple = (1, 2, 3);
+	/// let mut values = Vec::new();
+	/// tuple.for_each_mut(|value| {
+	///     *value *= 10;
+	///     values.push(*value);
+	/// });
+	/// assert_eq!(vec![10, 20, 30], values);
+	/// assert_eq!((10, 20, 30), tuple);
+	/// ```
+	#[inline]
+	fn for_each_mut<F: core::ops::FnMut(&mut T)>(&mut self, f: F) {
+		self.iter_mut().for_each(f);
+	}
+
+	/// Consumes this tuple and returns an iterator over its elements satisfying a predicate, in order.
+	///
+	/// The remaining elements are yielded by an iterator, for the same reason as in [`dedup_consecutive`](HomogeneousTuple::dedup_consecutive).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 2, 3, 4, 5);
+	/// let even: Vec<i32> = tuple.filter(|value| value % 2 == 0).collect();
+	/// assert_eq!(vec![2, 4], even);
+	/// assert_eq!(None, HomogeneousTuple::<i32>::filter((), |_| true).next());
+	/// ```
+	#[inline]
+	fn filter<F: core::ops::FnMut(&T) -> bool>(self, f: F) -> impl Iterator<Item = T> {
+		self.into_array().into_iter().filter(f)
+	}
+
+	/// Consumes this tuple and returns an iterator over its elements, in order, skipping those equal to the previous one.
+	///
+	/// The arity of a tuple can't shrink depending on its values, so the collapsed elements are yielded by an iterator instead.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let tuple = (1, 1, 2, 2, 2, 3, 1);
+	/// let deduped: Vec<i32> = tuple.dedup_consecutive().collect();
+	/// assert_eq!(vec![1, 2, 3, 1], deduped);
+	/// assert_eq!(None, HomogeneousTuple::<i32>::dedup_consecutive(()).next());
+	/// ```
+	#[inline]
+	fn dedup_consecutive(self) -> impl Iterator<Item = T>
+	where
+		T: PartialEq,
+	{
+		let mut iter = self.into_array().into_iter().peekable();
+		core::iter::from_fn(move || {
+			let value = iter.next()?;
+			while iter.next_if(|next| *next == value).is_some() {}
+			Some(value)
+		})
+	}
+
+	/// Consumes this tuple and collects its elements into a [`Vec`](alloc::vec::Vec), in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HomogeneousTuple;
+	/// let vec = (1, 2, 3).to_vec();
+	/// assert_eq!(3, vec.len());
+	/// assert_eq!(vec![1, 2, 3], vec);
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn to_vec(self) -> alloc::vec::Vec<T> {
+		self.into_array().into_iter().collect()
+	}
+}
+
+/// Tuples that can be zipped with another tuple of the same arity. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait ZippableTuple<T: Tuple>: Tuple {
+	/// This tuple zipped with another tuple.
+	type Zipped: Tuple;
+
+	/// Zips this tuple with another tuple, pairing their elements together.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::ZippableTuple;
+	/// let tuple = (1, 2, 3);
+	/// let other = ("a", "b", "c");
+	/// let zipped = tuple.zip(other);
+	/// assert_eq!(((1, "a"), (2, "b"), (3, "c")), zipped);
+	/// ```
+	fn zip(self, other: T) -> Self::Zipped;
+}
+
+/// Tuples that can be zipped with another tuple of any arity, stopping at the shortest of the two. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity).
+pub trait ZipShortest<T: Tuple>: Tuple {
+	/// This tuple zipped with the common prefix of another tuple.
+	type Output: Tuple;
+
+	/// Zips this tuple with another tuple, pairing their elements together and dropping the surplus elements of the longest one.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::ZipShortest;
+	/// assert_eq!(((1, "a"), (2, "b")), (1, 2, 3).zip_shortest(("a", "b")));
+	/// assert_eq!(((1, "a"), (2, "b")), (1, 2).zip_shortest(("a", "b", "c")));
+	/// assert_eq!(((1, "a"),), (1,).zip_shortest(("a",)));
+	/// assert_eq!((), (1, 2).zip_shortest(()));
+	/// ```
+	fn zip_shortest(self, other: T) -> Self::Output;
+}
+
+/// Tuples of tuples whose inner tuples can each be extended with the element of another tuple at the same index, used by [`zip!`].
+#[doc(hidden)]
+pub trait ZipAppendTuple<T: Tuple>: Tuple {
+	/// This tuple with each of its inner tuples extended with an element of another tuple.
+	type Output: Tuple;
+
+	/// Appends each element of another tuple to the inner tuple of this tuple at the same index.
+	fn zip_append(self, other: T) -> Self::Output;
+}
+
+/// Tuples that can be interleaved with another tuple of the same arity. Implemented for sized tuples of arity 0 to half the [maximum arity](crate#maximum-arity).
+pub trait InterleaveTuple<T: Tuple>: Tuple {
+	/// This tuple interleaved with another tuple.
+	type Output: Tuple;
+
+	/// Interleaves this tuple with another tuple, alternating between their elements starting with this tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::InterleaveTuple;
+	/// let tuple = (1, 3, 5);
+	/// let other = ("a", "b", "c");
+	/// assert_eq!((1, "a", 3, "b", 5, "c"), tuple.interleave(other));
+	/// assert_eq!((), ().interleave(()));
+	/// ```
+	fn interleave(self, other: T) -> Self::Output;
+}
+
+/// Tuples of pairs that can be unzipped into two tuples. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait UnzippableTuple: Tuple {
+	/// The tuple made of the left elements of each pair.
+	type Lefts: Tuple;
+
+	/// The tuple made of the right elements of each pair.
+	type Rights: Tuple;
+
+	/// Consumes this tuple and unzips its pairs into two tuples.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{UnzippableTuple, ZippableTuple};
+	/// let tuple = ((1, "a"), (2, "b"));
+	/// let (lefts, rights) = tuple.unzip();
+	/// assert_eq!(((1, 2), ("a", "b")), (lefts, rights));
+	/// assert_eq!(tuple, lefts.zip(rights));
+	/// ```
+	fn unzip(self) -> (Self::Lefts, Self::Rights);
+}
+
+/// Tuples that can be split in two at a given index. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity).
+pub trait SplitTuple<const INDEX: usize>: Tuple {
+	/// The elements of this tuple before the given index.
+	type Left: JoinableTuple<Self::Right, Join = Self>;
+
+	/// The elements of this tuple starting from the given index.
+	type Right: JoinableTuple<Self::Left>;
+
+	/// Consumes this tuple and splits it in two at the given index.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::SplitTuple;
+	/// let tuple = (1, 2, 3, 4, 5);
+	/// assert_eq!(((1, 2), (3, 4, 5)), SplitTuple::<2>::split_at(tuple));
+	/// assert_eq!(((), (1, 2, 3, 4, 5)), SplitTuple::<0>::split_at(tuple));
+	/// assert_eq!(((1, 2, 3, 4, 5), ()), SplitTuple::<5>::split_at(tuple));
+	/// ```
+	///
+	/// The index can't be greater than the arity of the tuple.
+	///
+	/// ```compile_fail
+	/// # use tupl::SplitTuple;
+	/// let tuple = (1, 2, 3);
+	/// let split = SplitTuple::<4>::split_at(tuple);
+	/// ```
+	fn split_at(self) -> (Self::Left, Self::Right);
+
+	/// Consumes this tuple and splits it in two, keeping its first `INDEX` elements on the left. This is an alias of [`split_at`](SplitTuple::split_at).
+	///
+	/// Both halves can be joined back together into the original tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{JoinableTuple, SplitTuple};
+	/// let tuple = (1, 2, 3, 4);
+	/// let (left, right) = SplitTuple::<2>::truncate_n(tuple);
+	/// assert_eq!(((1, 2), (3, 4)), (left, right));
+	/// assert_eq!(tuple, left.join(right));
+	/// ```
+	#[inline]
+	fn truncate_n(self) -> (Self::Left, Self::Right) {
+		self.split_at()
+	}
+}
+
+/// Tuples from which the first elements can be kept. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity).
+pub trait TakeTuple<const COUNT: usize>: Tuple {
+	/// The first elements of this tuple.
+	type Taken: Tuple;
+
+	/// Consumes this tuple and returns its first elements, dropping the others.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::TakeTuple;
+	/// let tuple = (1, 2, 3, 4);
+	/// assert_eq!((1, 2), TakeTuple::<2>::take(tuple));
+	/// assert_eq!((), TakeTuple::<0>::take(tuple));
+	/// assert_eq!((1, 2, 3, 4), TakeTuple::<4>::take(tuple));
+	/// ```
+	///
+	/// The count can't be greater than the arity of the tuple.
+	///
+	/// ```compile_fail
+	/// # use tupl::TakeTuple;
+	/// let tuple = (1, 2, 3);
+	/// let taken = TakeTuple::<4>::take(tuple);
+	/// ```
+	fn take(self) -> Self::Taken;
+}
+
+/// Tuples from which the first elements can be dropped. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity).
+pub trait SkipTuple<const COUNT: usize>: Tuple {
+	/// The elements of this tuple after the skipped ones.
+	type Skipped: Tuple;
+
+	/// Consumes this tuple and returns its elements after the first ones, dropping those.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::SkipTuple;
+	/// let tuple = (1, 2, 3, 4);
+	/// assert_eq!((3, 4), SkipTuple::<2>::skip(tuple));
+	/// assert_eq!((1, 2, 3, 4), SkipTuple::<0>::skip(tuple));
+	/// assert_eq!((), SkipTuple::<4>::skip(tuple));
+	/// ```
+	///
+	/// The count can't be greater than the arity of the tuple.
+	///
+	/// ```compile_fail
+	/// # use tupl::SkipTuple;
+	/// let tuple = (1, 2, 3);
+	/// let skipped = SkipTuple::<4>::skip(tuple);
+	/// ```
+	fn skip(self) -> Self::Skipped;
+}
+
+/// Tuples whose elements can be cloned into overlapping windows of a given size. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity), for sizes 1 to the maximum const arity.
+pub trait WindowsTuple<const SIZE: usize>: Tuple {
+	/// The windows of this tuple.
+	type Output: Tuple;
+
+	/// Consumes this tuple and returns all of its contiguous windows of the given size, in order.
+	///
+	/// Each element is cloned into every window it belongs to, except the last one which takes the element itself,
+	/// so only the elements belonging to several windows need to implement [`Clone`].
+	/// If the size is greater than the arity of the tuple, there are no windows.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::WindowsTuple;
+	/// let tuple = (1, 2, 3, 4);
+	/// assert_eq!(((1, 2), (2, 3), (3, 4)), WindowsTuple::<2>::windows(tuple));
+	/// assert_eq!(((1, 2, 3), (2, 3, 4)), WindowsTuple::<3>::windows(tuple));
+	/// assert_eq!(((1, 2, 3, 4),), WindowsTuple::<4>::windows(tuple));
+	/// assert_eq!((), WindowsTuple::<5>::windows(tuple));
+	///
+	/// #[derive(Debug, PartialEq)]
+	/// struct Unique(i32);
+	///
+	/// let tuple = (Unique(1), 2, 3, Unique(4));
+	/// assert_eq!(((Unique(1), 2, 3), (2, 3, Unique(4))), WindowsTuple::<3>::windows(tuple));
+	/// assert_eq!(((Unique(1),), (Unique(2),)), WindowsTuple::<1>::windows((Unique(1), Unique(2))));
+	/// assert_eq!((), WindowsTuple::<3>::windows((Unique(1), Unique(2))));
+	/// ```
+	///
+	/// The size of the windows can't be zero.
+	///
+	/// ```compile_fail
+	/// # use tupl::WindowsTuple;
+	/// let tuple = (1, 2, 3, 4);
+	/// let windows = WindowsTuple::<0>::windows(tuple);
+	/// ```
+	fn windows(self) -> Self::Output;
+}
+
+/// Tuples that can be split into consecutive chunks of a given size. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity), for sizes 1 to the maximum const arity dividing their arity.
+pub trait ChunksTuple<const SIZE: usize>: Tuple {
+	/// The chunks of this tuple.
+	type Output: Tuple;
+
+	/// Consumes this tuple and splits it into consecutive chunks of the given size, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::ChunksTuple;
+	/// let tuple = (1, 2, 3, 4, 5, 6);
+	/// assert_eq!(((1, 2), (3, 4), (5, 6)), ChunksTuple::<2>::chunks(tuple));
+	/// assert_eq!(((1, 2, 3), (4, 5, 6)), ChunksTuple::<3>::chunks(tuple));
+	/// assert_eq!(((1, 2, 3, 4, 5, 6),), ChunksTuple::<6>::chunks(tuple));
+	/// assert_eq!((), ChunksTuple::<2>::chunks(()));
+	/// ```
+	///
+	/// The arity of the tuple must be a multiple of the size of the chunks.
+	///
+	/// ```compile_fail
+	/// # use tupl::ChunksTuple;
+	/// let tuple = (1, 2, 3, 4, 5);
+	/// let chunks = ChunksTuple::<2>::chunks(tuple);
+	/// ```
+	fn chunks(self) -> Self::Output;
+}
+
+/// Tuples whose elements at two given indices share the same type, allowing them to be swapped. Implemented for sized tuples of arity 1 to the [maximum const arity](crate#maximum-arity).
+pub trait SwappableTuple<const I: usize, const J: usize>:
+	IndexableTuple<I> + IndexableTuple<J>
+{
+	/// Consumes this tuple and swaps the elements at the given indices.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::SwappableTuple;
+	/// let tuple = (1, 2, 3);
+	/// assert_eq!((3, 2, 1), SwappableTuple::<0, 2>::swap(tuple));
+	/// assert_eq!((1, 2, 3), SwappableTuple::<0, 0>::swap(tuple));
+	/// ```
+	///
+	/// The elements at both indices must share the same type.
+	///
+	/// ```compile_fail
+	/// # use tupl::SwappableTuple;
+	/// let tuple = (1, "a", 3);
+	/// let swapped = SwappableTuple::<0, 1>::swap(tuple);
+	/// ```
+	fn swap(self) -> Self;
+}
+
+/// Tuples whose element at a given index can be replaced by a value of any type. Implemented for sized tuples of arity 1 to the [maximum const arity](crate#maximum-arity).
+pub trait ReplaceTuple<const INDEX: usize>: IndexableTuple<INDEX> {
+	/// This tuple with the element at the given index replaced by an element `U`.
+	type Replaced<U>: Tuple;
+
+	/// Consumes this tuple and replaces the element at the given index, returning it along with the new tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::ReplaceTuple;
+	/// let tuple = (1, "a", 3);
+	/// let (old, tuple) = ReplaceTuple::<1>::replace(tuple, true);
+	/// assert_eq!(("a", (1, true, 3)), (old, tuple));
+	/// ```
+	fn replace<U>(self, value: U) -> (Self::Value, Self::Replaced<U>);
+}
+
+/// Tuples whose element at a given index can be mapped to a value of any type. Implemented for sized tuples of arity 1 to the [maximum const arity](crate#maximum-arity).
+pub trait MapAtTuple<const INDEX: usize>: IndexableTuple<INDEX> {
+	/// This tuple with the element at the given index replaced by an element `U`.
+	type Mapped<U>: Tuple;
+
+	/// Consumes this tuple and maps the element at the given index to a new value, keeping the other elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::MapAtTuple;
+	/// let tuple = (1, "abc", 3.0);
+	/// assert_eq!((2, "abc", 3.0), MapAtTuple::<0>::map_at(tuple, |value: i32| value * 2));
+	/// assert_eq!((1, 3, 3.0), MapAtTuple::<1>::map_at(tuple, |value: &str| value.len()));
+	/// assert_eq!((1, "abc", true), MapAtTuple::<2>::map_at(tuple, |value: f64| value > 2.0));
+	/// ```
+	fn map_at<U, F: core::ops::FnOnce(Self::Value) -> U>(self, f: F) -> Self::Mapped<U>;
+}
+
+/// Tuples in which an element can be inserted at a given index. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity) minus one.
+pub trait InsertTuple<const INDEX: usize>: Tuple {
+	/// This tuple with an element `U` inserted at the given index.
+	type Inserted<U>: Tuple;
+
+	/// Consumes this tuple and inserts a value at the given index, shifting the following elements to the right.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::InsertTuple;
+	/// let tuple = (1, 3);
+	/// assert_eq!((0, 1, 3), InsertTuple::<0>::insert(tuple, 0));
+	/// assert_eq!((1, 2, 3), InsertTuple::<1>::insert(tuple, 2));
+	/// assert_eq!((1, 3, 4), InsertTuple::<2>::insert(tuple, 4));
+	/// ```
+	///
+	/// The index can't be greater than the arity of the tuple.
+	///
+	/// ```compile_fail
+	/// # use tupl::InsertTuple;
+	/// let tuple = (1, 3);
+	/// let inserted = InsertTuple::<3>::insert(tuple, 4);
+	/// ```
+	fn insert<U>(self, value: U) -> Self::Inserted<U>;
+}
+
+/// Tuples whose element at a given index can be removed. Implemented for sized tuples of arity 1 to the [maximum const arity](crate#maximum-arity).
+pub trait RemoveTuple<const INDEX: usize>: IndexableTuple<INDEX> {
+	/// This tuple without the element at the given index.
+	type Removed: Tuple;
+
+	/// Consumes this tuple and removes the element at the given index, returning it along with the remaining elements.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::RemoveTuple;
+	/// let tuple = (1, "a", 3.0);
+	/// assert_eq!((1, ("a", 3.0)), RemoveTuple::<0>::remove(tuple));
+	/// assert_eq!(("a", (1, 3.0)), RemoveTuple::<1>::remove(tuple));
+	/// assert_eq!((3.0, (1, "a")), RemoveTuple::<2>::remove(tuple));
+	/// ```
+	fn remove(self) -> (Self::Value, Self::Removed);
+}
+
+/// Tuples whose element at a given index can be removed by replacing it with their tail. Implemented for sized tuples of arity 1 to the [maximum const arity](crate#maximum-arity).
+pub trait SwapRemoveTuple<const INDEX: usize>: IndexableTuple<INDEX> {
+	/// This tuple without the element at the given index, with its tail moved to that index.
+	type Removed: Tuple;
+
+	/// Consumes this tuple and removes the element at the given index, returning it along with the remaining elements.
+	///
+	/// The tail of this tuple takes the place of the removed element, the other elements keeping their positions.
+	/// Unlike [`remove`](RemoveTuple::remove), the order of the remaining elements is therefore not preserved.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{RemoveTuple, SwapRemoveTuple};
+	/// let tuple = (1, 2, 3, 4);
+	/// assert_eq!((2, (1, 4, 3)), SwapRemoveTuple::<1>::swap_remove(tuple));
+	/// assert_eq!((2, (1, 3, 4)), RemoveTuple::<1>::remove(tuple));
+	/// assert_eq!((1, (4, 2, 3)), SwapRemoveTuple::<0>::swap_remove(tuple));
+	/// assert_eq!((4, (1, 2, 3)), SwapRemoveTuple::<3>::swap_remove(tuple));
+	/// ```
+	fn swap_remove(self) -> (Self::Value, Self::Removed);
+}
+
+/// Tuples that can be compared for equality while ignoring the element at a given index. Implemented for sized tuples of arity 1 to the [maximum const arity](crate#maximum-arity).
+pub trait EqIgnoringTuple<const INDEX: usize>: Tuple {
+	/// Returns whether this tuple is equal to another tuple, without comparing the elements at the given index.
+	///
+	/// Only the other elements are required to implement [`PartialEq`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::EqIgnoringTuple;
+	/// struct Timestamp(u64);
+	///
+	/// let tuple = ("event", 1, Timestamp(100));
+	/// assert!(EqIgnoringTuple::<2>::eq_ignoring(&tuple, &("event", 1, Timestamp(200))));
+	/// assert!(!EqIgnoringTuple::<2>::eq_ignoring(&tuple, &("event", 2, Timestamp(100))));
+	/// ```
+	fn eq_ignoring(&self, other: &Self) -> bool;
+}
+
+/// Tuples whose elements can be hashed selectively. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait HashIndicesTuple: Tuple {
+	/// Feeds the elements at the given indices into a [`Hasher`](core::hash::Hasher), in the order of the indices.
+	///
+	/// Indices that are out of bounds are skipped.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::HashIndicesTuple;
+	/// # use std::hash::{DefaultHasher, Hasher};
+	/// fn hash_of(tuple: &(&str, u32, u64), indices: &[usize]) -> u64 {
+	///     let mut hasher = DefaultHasher::new();
+	///     tuple.hash_indices(indices, &mut hasher);
+	///     hasher.finish()
+	/// }
+	///
+	/// let tuple = ("event", 1, 100);
+	/// let other = ("event", 1, 200);
+	/// assert_eq!(hash_of(&tuple, &[0, 1, 5]), hash_of(&other, &[0, 1]));
+	/// assert_ne!(hash_of(&tuple, &[0, 1, 2]), hash_of(&other, &[0, 1, 2]));
+	/// ```
+	fn hash_indices<H: core::hash::Hasher>(&self, indices: &[usize], state: &mut H);
+}
+
+/// Tuples whose elements can be rotated. Implemented for sized tuples of arity 1 to the [maximum arity](crate#maximum-arity).
+pub trait RotatableTuple: NonEmptyTuple {
+	/// This tuple with its elements rotated one position to the left.
+	type RotatedLeft: RotatableTuple<Tail = Self::Head, RotatedRight = Self>;
+
+	/// This tuple with its elements rotated one position to the right.
+	type RotatedRight: RotatableTuple<Head = Self::Tail, RotatedLeft = Self>;
+
+	/// Consumes this tuple and rotates its elements one position to the left, moving the head to the end.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::RotatableTuple;
+	/// assert_eq!((1,), (1,).rotate_left());
+	/// assert_eq!((2, 1), (1, 2).rotate_left());
+	/// assert_eq!((2, 3, 4, 1), (1, 2, 3, 4).rotate_left());
+	/// ```
+	fn rotate_left(self) -> Self::RotatedLeft;
+
+	/// Consumes this tuple and rotates its elements one position to the right, moving the tail to the front.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::RotatableTuple;
+	/// assert_eq!((1,), (1,).rotate_right());
+	/// assert_eq!((2, 1), (1, 2).rotate_right());
+	/// assert_eq!((4, 1, 2, 3), (1, 2, 3, 4).rotate_right());
+	/// ```
+	fn rotate_right(self) -> Self::RotatedRight;
+}
+
+/// Tuples whose elements can be rotated by a given number of positions. Implemented for sized tuples of arity 0 to the [maximum const arity](crate#maximum-arity), for counts 0 to the maximum const arity.
+pub trait RotateByTuple<const COUNT: usize>: Tuple {
+	/// This tuple with its elements rotated by the given number of positions to the left.
+	type Output: Tuple;
+
+	/// Consumes this tuple and rotates its elements by the given number of positions to the left, moving its first elements to the end.
+	///
+	/// The count is taken modulo the arity of the tuple, so rotating by zero or by a multiple of the arity leaves it unchanged.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::RotateByTuple;
+	/// let tuple = (1, 2, 3, 4, 5);
+	/// assert_eq!((3, 4, 5, 1, 2), RotateByTuple::<2>::rotate_by(tuple));
+	/// assert_eq!((5, 1, 2, 3, 4), RotateByTuple::<4>::rotate_by(tuple));
+	/// assert_eq!((1, 2, 3, 4, 5), RotateByTuple::<0>::rotate_by(tuple));
+	/// assert_eq!((1, 2, 3, 4, 5), RotateByTuple::<5>::rotate_by(tuple));
+	/// assert_eq!((3, 4, 5, 1, 2), RotateByTuple::<7>::rotate_by(tuple));
+	/// assert_eq!(("b", 1), RotateByTuple::<1>::rotate_by((1, "b")));
+	/// assert_eq!((), RotateByTuple::<3>::rotate_by(()));
+	/// ```
+	///
+	/// Counts are only implemented up to the maximum const arity, so larger counts don't compile even though they would be reduced modulo the arity.
+	///
+	/// ```compile_fail
+	/// # use tupl::RotateByTuple;
+	/// let tuple = (1, 2, 3, 4, 5);
+	/// let rotated = RotateByTuple::<33>::rotate_by(tuple);
+	/// ```
+	fn rotate_by(self) -> Self::Output;
+}
+
+/// Tuples of tuples that can be flattened into a single tuple. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait FlattenTuple: Tuple {
+	/// This tuple with its inner tuples joined together.
+	type Flattened: Tuple;
+
+	/// Consumes this tuple and joins its inner tuples together, from left to right.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::FlattenTuple;
+	/// assert_eq!((1, 2, 3, 4), ((1, 2), (3, 4)).flatten());
+	/// assert_eq!((1, 2, 3), ((1,), (), (2, 3)).flatten());
+	/// assert_eq!((), ().flatten());
+	/// ```
+	fn flatten(self) -> Self::Flattened;
+}
+
+/// Tuples of arrays of `T` that can be concatenated together. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+#[cfg(feature = "alloc")]
+pub trait ConcatArraysTuple<T>: Tuple {
+	/// Consumes this tuple and concatenates its arrays into a [`Vec`](alloc::vec::Vec), from left to right.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::ConcatArraysTuple;
+	/// let tuple = ([1, 2], [3, 4, 5], [], [6]);
+	/// assert_eq!(vec![1, 2, 3, 4, 5, 6], tuple.concat_arrays());
+	/// assert_eq!(Vec::<i32>::new(), ().concat_arrays());
+	/// ```
+	fn concat_arrays(self) -> alloc::vec::Vec<T>;
+}
+
+/// Tuples of tuples whose inner arities can be inspected. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait NestedTuple: Tuple {
+	/// Returns an iterator over the arity of each inner tuple of this tuple, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::NestedTuple;
+	/// let tuple = ((1, 2), (3, 4, 5));
+	/// assert_eq!(vec![2, 3], tuple.nested_arities().collect::<Vec<_>>());
+	/// assert_eq!(vec![0, 1], ((), ("a",)).nested_arities().collect::<Vec<_>>());
+	/// assert_eq!(0, ().nested_arities().len());
+	/// ```
+	fn nested_arities(&self) -> impl DoubleEndedIterator<Item = usize> + ExactSizeIterator;
+}
+
+/// Tuples of [`Option`]s that can be transposed into an [`Option`] of a tuple. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait TransposeOption: Tuple {
+	/// This tuple with each of its elements unwrapped from their [`Option`].
+	type Output: Tuple;
+
+	/// Consumes this tuple and returns `Some` with all of its values if they are all `Some`, or `None` otherwise.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::TransposeOption;
+	/// assert_eq!(Some((1, "a", 2.0)), (Some(1), Some("a"), Some(2.0)).transpose());
+	/// assert_eq!(None, (Some(1), None::<&str>, Some(2.0)).transpose());
+	/// assert_eq!(Some(()), TransposeOption::transpose(()));
+	/// ```
+	///
+	/// The empty tuple also implements [`TransposeResult`], so it has to be transposed through the trait when both are in scope.
+	fn transpose(self) -> Option<Self::Output>;
+}
+
+/// Tuples of [`Result`]s sharing the same error type that can be transposed into a [`Result`] of a tuple. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait TransposeResult<E>: Tuple {
+	/// This tuple with each of its elements unwrapped from their [`Result`].
+	type Output: Tuple;
+
+	/// Consumes this tuple and returns `Ok` with all of its values if they are all `Ok`, or the first `Err` otherwise.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::TransposeResult;
+	/// assert_eq!(Ok((1, "a")), (Ok::<_, &str>(1), Ok("a")).transpose());
+	/// assert_eq!(Err("first"), (Ok(1), Err::<&str, _>("first"), Err::<f64, _>("second")).transpose());
+	///
+	/// let unit: Result<(), &str> = TransposeResult::transpose(());
+	/// assert_eq!(Ok(()), unit);
+	/// ```
+	///
+	/// The empty tuple also implements [`TransposeOption`], so calling `transpose` as a method on it is ambiguous when both traits are in scope.
+	///
+	/// ```compile_fail
+	/// use tupl::*;
+	/// let unit: Result<(), &str> = ().transpose();
+	/// ```
+	fn transpose(self) -> Result<Self::Output, E>;
+}
+
+/// Tuples whose elements can all be borrowed as the same type. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait TupleAsRef<'a, U: ?Sized + 'a>: Tuple {
+	/// The iterator over the borrowed elements of this tuple.
+	type Iterator: DoubleEndedIterator<Item = &'a U> + ExactSizeIterator;
+
+	/// Returns an iterator over the elements of this tuple, each borrowed as `U` using [`AsRef`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::TupleAsRef;
+	/// let tuple = (String::from("a"), "b", Box::<str>::from("c"));
+	/// let strs: Vec<&str> = tuple.tuple_as_ref().collect();
+	/// assert_eq!(vec!["a", "b", "c"], strs);
+	/// ```
+	fn tuple_as_ref(&'a self) -> Self::Iterator;
+}
+
+/// Tuples whose elements can all be converted into the same type. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait MapIntoTuple<U>: Tuple {
+	/// This tuple with each of its elements converted into `U`.
+	type Output: Tuple;
+
+	/// Consumes this tuple and converts each of its elements into `U` using [`Into`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::MapIntoTuple;
+	/// let tuple: (u64, u64) = (1u8, 2u16).map_into();
+	/// assert_eq!((1, 2), tuple);
+	/// assert_eq!((1.0, 2.0), MapIntoTuple::<f64>::map_into((1u8, 2.0f32)));
+	/// ```
+	///
+	/// All the elements must be convertible into `U`.
+	///
+	/// ```compile_fail
+	/// # use tupl::MapIntoTuple;
+	/// let tuple: (u8, u8) = (1u8, 2u16).map_into();
+	/// ```
+	fn map_into(self) -> Self::Output;
+
+	/// Consumes this tuple and returns an iterator over its elements converted into `U` using [`Into`], from its tail to its head.
+	///
+	/// The elements are converted in that same order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{HomogeneousTuple, MapIntoTuple};
+	/// let tuple = (1u8, 2u16, 3u32);
+	/// let converted: (u64, u64, u64) = tuple.map_into();
+	/// assert_eq!((1, 2, 3), converted);
+	///
+	/// let forward: Vec<u64> = converted.into_array().into_iter().collect();
+	/// assert_eq!(vec![1, 2, 3], forward);
+	/// let reversed: Vec<u64> = tuple.rev_tuple_into().collect();
+	/// assert_eq!(vec![3, 2, 1], reversed);
+	/// let unreversed: Vec<u64> = tuple.rev_tuple_into().rev().collect();
+	/// assert_eq!(vec![1, 2, 3], unreversed);
+	/// ```
+	fn rev_tuple_into(self) -> impl DoubleEndedIterator<Item = U> + ExactSizeIterator;
+}
+
+/// Tuples whose elements all implement [`Default`]. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+///
+/// The standard library only implements [`Default`] for tuples of arity up to 12, whereas this trait covers every supported arity.
+/// Tuples of arity 13 to 16 require the default `arity-16` feature, those of arity 17 to 32 the `arity-32` feature, and larger ones the `large-tuples` feature.
+pub trait DefaultTuple: Tuple {
+	/// Creates a tuple with each of its elements set to their default value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::DefaultTuple;
+	/// let tuple: (i32, String, Option<u8>) = DefaultTuple::default_tuple();
+	/// assert_eq!((0, String::new(), None), tuple);
+	/// assert_eq!((), <()>::default_tuple());
+	/// ```
+	///
+	/// Tuples larger than the ones supported by the standard library can also be created.
+	///
+	/// ```
+	/// # #[cfg(any(feature = "arity-16", feature = "arity-32", feature = "large-tuples"))] {
+	/// # use tupl::{DefaultTuple, HomogeneousTuple};
+	/// type Large = (
+	///     u8, u8, u8, u8, u8, u8, u8, u8,
+	///     u8, u8, u8, u8, u8, u8, u8, u8,
+	/// );
+	///
+	/// let tuple = Large::default_tuple();
+	/// assert_eq!([0; 16], tuple.into_array());
+	/// # }
+	/// ```
+	///
+	/// Tuples of arity 20 require the `arity-32` feature.
+	///
+	/// ```
+	/// # #[cfg(any(feature = "arity-32", feature = "large-tuples"))] {
+	/// # use tupl::{DefaultTuple, HomogeneousTuple};
+	/// type Large = (
+	///     u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+	///     u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+	/// );
+	///
+	/// let tuple = Large::default_tuple();
+	/// assert_eq!([0; 20], tuple.into_array());
+	/// # }
+	/// ```
+	fn default_tuple() -> Self;
+}
+
+/// A generic transformation that can be applied to values of any type, used to map the elements of heterogeneous tuples with [`MapTuple`].
+pub trait TupleMapper {
+	/// The type a value of type `T` is mapped to.
+	type Output<T>;
+
+	/// Maps a value of any type to a new value.
+	fn map<T>(&mut self, value: T) -> Self::Output<T>;
+}
+
+/// Tuples whose elements can all be mapped by the same [`TupleMapper`], whatever their types. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait MapTuple: Tuple {
+	/// This tuple with each of its elements mapped by the mapper `M`.
+	type Mapped<M: TupleMapper>: Tuple;
+
+	/// Consumes this tuple and maps each of its elements using a mapper, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{MapTuple, TupleMapper};
+	/// struct WrapSome;
+	///
+	/// impl TupleMapper for WrapSome {
+	///     type Output<T> = Option<T>;
+	///
+	///     fn map<T>(&mut self, value: T) -> Option<T> {
+	///         Some(value)
+	///     }
+	/// }
+	///
+	/// let tuple: (Option<i32>, Option<&str>) = (1, "x").map_each(WrapSome);
+	/// assert_eq!((Some(1), Some("x")), tuple);
+	/// assert_eq!((), ().map_each(WrapSome));
+	/// ```
+	///
+	/// The mapper can keep a state between elements.
+	///
+	/// ```
+	/// # use tupl::{MapTuple, TupleMapper};
+	/// struct Count(usize);
+	///
+	/// impl TupleMapper for Count {
+	///     type Output<T> = (usize, T);
+	///
+	///     fn map<T>(&mut self, value: T) -> (usize, T) {
+	///         self.0 += 1;
+	///         (self.0, value)
+	///     }
+	/// }
+	///
+	/// assert_eq!(((1, 'a'), (2, 2.0), (3, "c")), ('a', 2.0, "c").map_each(Count(0)));
+	/// ```
+	fn map_each<M: TupleMapper>(self, mapper: M) -> Self::Mapped<M>;
+}
+
+/// A folding function that can be applied to values of type `T`, used to fold the elements of heterogeneous tuples with [`FoldTuple`].
+///
+/// Implementing it for several types, or generically over `T`, allows folding tuples whose elements have different types.
+pub trait TupleFolder<Acc, T> {
+	/// Folds a value into the accumulator, returning the new accumulator.
+	fn fold(&mut self, acc: Acc, value: T) -> Acc;
+}
+
+/// Tuples whose elements can all be folded by the same [`TupleFolder`], whatever their types. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait FoldTuple<Acc, F>: Tuple {
+	/// Consumes this tuple and folds its elements into an accumulator using a folder, in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::{FoldTuple, TupleFolder};
+	/// struct TotalLen;
+	///
+	/// impl<T> TupleFolder<usize, Vec<T>> for TotalLen {
+	///     fn fold(&mut self, acc: usize, value: Vec<T>) -> usize {
+	///         acc + value.len()
+	///     }
+	/// }
+	///
+	/// impl TupleFolder<usize, String> for TotalLen {
+	///     fn fold(&mut self, acc: usize, value: String) -> usize {
+	///         acc + value.len()
+	///     }
+	/// }
+	///
+	/// impl<T, const N: usize> TupleFolder<usize, [T; N]> for TotalLen {
+	///     fn fold(&mut self, acc: usize, _: [T; N]) -> usize {
+	///         acc + N
+	///     }
+	/// }
+	///
+	/// let tuple = (vec![1u8, 2], String::from("abc"), [0u16; 4]);
+	/// assert_eq!(9, tuple.fold_each(0, TotalLen));
+	/// assert_eq!(0, ().fold_each(0, TotalLen));
+	/// ```
+	///
+	/// The elements are folded in order.
+	///
+	/// ```
+	/// # use tupl::{FoldTuple, TupleFolder};
+	/// # use std::fmt::Debug;
+	/// struct Concat;
+	///
+	/// impl<T: Debug> TupleFolder<String, T> for Concat {
+	///     fn fold(&mut self, acc: String, value: T) -> String {
+	///         format!("{acc}{value:?}")
+	///     }
+	/// }
+	///
+	/// assert_eq!("1'a'\"b\"", (1, 'a', "b").fold_each(String::new(), Concat));
+	/// ```
+	fn fold_each(self, init: Acc, folder: F) -> Acc;
+}
+
+/// Tuples whose elements can be paired with their index. Implemented for sized tuples of arity 0 to the [maximum arity](crate#maximum-arity).
+pub trait EnumerateTuple: Tuple {
+	/// This tuple with each of its elements paired with its index.
+	type Output: Tuple;
+
+	/// Consumes this tuple and pairs each of its elements with its index, as `(index, element)`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use tupl::EnumerateTuple;
+	/// assert_eq!(((0, "a"), (1, 2.0), (2, 'c')), ("a", 2.0, 'c').enumerate());
+	/// assert_eq!((), ().enumerate());
+	/// ```
+	fn enumerate(self) -> Self::Output;
+}
+
+/// The [maximum arity](crate#maximum-arity) of the tuples the traits are implemented for, as selected through the cargo features.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::MAX_SUPPORTED_ARITY;
+/// assert!(MAX_SUPPORTED_ARITY >= 8);
+/// ```
+pub const MAX_SUPPORTED_ARITY: usize = tupl_macros::max_arity!();
+
+/// Creates a tuple of references all pointing to the same value, whose arity is inferred from its type.
+///
+/// Every reference borrows `value` for the same lifetime `'a`, so the tuple can't outlive the value.
+/// The arity can't be given as a const parameter, but the [`tuple!`] macro can be used to create such a tuple with an explicit arity.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::splat_ref;
+/// let value = 5;
+/// let tuple: (&i32, &i32, &i32) = splat_ref(&value);
+/// assert_eq!((&5, &5, &5), tuple);
+/// assert_eq!(tuple, tupl::tuple![&value; 3]);
+/// ```
+#[inline]
+pub fn splat_ref<'a, T: ?Sized, Tup: HomogeneousTuple<&'a T>>(value: &'a T) -> Tup {
+	Tup::repeat(value)
+}
+
+/// Returns the range of the valid indices of tuple `T`, from `0` to its arity.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::indices;
+/// const INDICES: core::ops::Range<usize> = indices::<(u8, u16, u32)>();
+/// assert_eq!(0..3, INDICES);
+/// assert!(indices::<()>().is_empty());
+/// ```
+#[inline]
+pub const fn indices<T: Tuple>() -> core::ops::Range<usize> {
+	0..T::ARITY
+}
+
+/// Returns whether the arity of tuple `T` is at least `min`.
+///
+/// This function is `const`, so it can be used within a `const` block to statically reject tuples that are too small.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::{assert_min_arity, Tuple};
+/// assert!(assert_min_arity::<(i32, i32)>(2));
+/// assert!(!assert_min_arity::<(i32,)>(2));
+///
+/// fn at_least_two<T: Tuple>(tuple: T) -> T {
+///     const { assert!(assert_min_arity::<T>(2)) };
+///     tuple
+/// }
+///
+/// assert_eq!((1, 2, 3), at_least_two((1, 2, 3)));
+/// ```
+///
+/// The assertion fails at compile time if the tuple is too small.
+///
+/// ```compile_fail
+/// # use tupl::{assert_min_arity, Tuple};
+/// fn at_least_three<T: Tuple>(tuple: T) -> T {
+///     const { assert!(assert_min_arity::<T>(3)) };
+///     tuple
+/// }
+///
+/// at_least_three((1, 2));
+/// ```
+#[inline]
+pub const fn assert_min_arity<T: Tuple>(min: usize) -> bool {
+	T::ARITY >= min
+}
+
+/// Returns whether the arity of tuple `T` is at most `max`.
+///
+/// This function is `const`, so it can be used within a `const` block to statically reject tuples that are too large.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::{assert_max_arity, Tuple};
+/// assert!(assert_max_arity::<(i32, i32)>(2));
+/// assert!(!assert_max_arity::<(i32, i32, i32)>(2));
+///
+/// const fn is_pair<T: Tuple>() -> bool {
+///     tupl::assert_min_arity::<T>(2) && assert_max_arity::<T>(2)
+/// }
+///
+/// const { assert!(is_pair::<(u8, char)>()) };
+/// ```
+///
+/// The assertion fails at compile time if the tuple is too large.
+///
+/// ```compile_fail
+/// # use tupl::{assert_max_arity, Tuple};
+/// fn at_most_two<T: Tuple>(tuple: T) -> T {
+///     const { assert!(assert_max_arity::<T>(2)) };
+///     tuple
+/// }
+///
+/// at_most_two((1, 2, 3));
+/// ```
+#[inline]
+pub const fn assert_max_arity<T: Tuple>(max: usize) -> bool {
+	T::ARITY <= max
+}
+
+/// Joins any number of tuples together into a single tuple, from left to right.
+///
+/// # Examples
+///
+/// ```
+/// let joined: (i32, i32, &str, f64, char, char) = tupl::join!((1, 2), ("a",), (), (4.0, 'e', 'f'));
+/// assert_eq!((1, 2, "a", 4.0, 'e', 'f'), joined);
+/// assert_eq!((), tupl::join!());
+/// ```
+#[macro_export]
+macro_rules! join {
+	() => {
+		()
+	};
+	($tuple:expr $(,)?) => {
+		$tuple
+	};
+	($tuple:expr, $other:expr $(, $others:expr)* $(,)?) => {
+		$crate::join!($crate::JoinableTuple::join($tuple, $other) $(, $others)*)
+	};
+}
+
+/// Zips any number of tuples of the same arity together, grouping their elements at the same index into a single tuple.
+///
+/// At least two tuples are required. Zipping empty tuples results in the empty tuple.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(((1, 3, 5), (2, 4, 6)), tupl::zip!((1, 2), (3, 4), (5, 6)));
+/// assert_eq!(((1, "a", 'a', 1.0),), tupl::zip!((1,), ("a",), ('a',), (1.0,)));
+/// assert_eq!(((1, 2), (3, 4)), tupl::zip!((1, 3), (2, 4)));
+/// assert_eq!((), tupl::zip!((), (), ()));
+/// ```
+///
+/// The tuples must all share the same arity.
+///
+/// ```compile_fail
+/// let zipped = tupl::zip!((1, 2), (3, 4), (5,));
+/// ```
+#[macro_export]
+macro_rules! zip {
+	($tuple:expr, $other:expr $(, $others:expr)* $(,)?) => {{
+		let zipped = $crate::ZippableTuple::zip($tuple, $other);
+		$(let zipped = $crate::ZipAppendTuple::zip_append(zipped, $others);)*
+		zipped
+	}};
+}
+
+/// Creates a tuple, either from a list of values or by repeating a value a given number of times.
+///
+/// The repetition form requires the value to implement [`Clone`] and the count to be an integer literal.
+/// The value is evaluated once, then cloned into every element but the last, which takes the value itself.
+///
+/// # Examples
+///
+/// ```
+/// # use tupl::DynTuple;
+/// assert_eq!((1, "a", 3.0), tupl::tuple![1, "a", 3.0]);
+/// assert_eq!((1,), tupl::tuple![1]);
+/// assert_eq!((), tupl::tuple![]);
+///
+/// let tuple: (u8, u8, u8, u8, u8) = tupl::tuple![0u8; 5];
+/// assert_eq!((0, 0, 0, 0, 0), tuple);
+/// assert_eq!(5, tuple.arity());
+///
+/// let tuple = tupl::tuple![String::from("a"); 2];
+/// assert_eq!((String::from("a"), String::from("a")), tuple);
+/// assert_eq!((), tupl::tuple![0; 0]);
+/// assert_eq!((7, 7, 7), tupl::tuple![7; 3usize]);
+/// assert_eq!((1, 1, 1, 1), tupl::tuple![1; 0x4]);
+/// ```
+#[macro_export]
+macro_rules! tuple {
+	($value:expr; $count:literal) => {
+		$crate::__repeat_tuple!(($value); $count)
+	};
+	($($values:expr),* $(,)?) => {
+		($($values,)*)
+	};
+}
+
+#[doc(hidden)]
+pub use tupl_macros::repeat_tuple as __repeat_tuple;
+
 // Implements all traits.
 tupl_macros::impl_traits!();